@@ -0,0 +1,80 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding a value after the yields of a generator,
+/// created by [`GenIter::append`].
+#[derive(Copy, Clone, Debug)]
+pub struct Append<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    tail: Option<T::Yield>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield the yields of the generator, then `value` as the last item
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// }).append(3);
+    ///
+    /// assert_eq!(g.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn append(self, value: T::Yield) -> Append<T> {
+        Append {
+            iter: self,
+            tail: Some(value),
+        }
+    }
+}
+
+impl<T> Iterator for Append<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(y) => Some(y),
+            None => self.tail.take(),
+        }
+    }
+}
+
+impl<T> FusedIterator for Append<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn append_trailer() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        }).append(3);
+
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), Some(3));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+    }
+}
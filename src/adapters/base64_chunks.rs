@@ -0,0 +1,181 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use GenIter;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// an iterator encoding chunks of the yielded bytes in base64,
+/// created by [`GenIter::base64_chunks`].
+#[derive(Clone, Debug)]
+pub struct Base64Chunks<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    chunk_bytes: usize,
+    buf: Vec<u8>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{
+    /// yield the base64 encoding, with the standard alphabet and padding,
+    /// of each `chunk_bytes` bytes, and of the remaining bytes at the end.
+    ///
+    /// each chunk is padded, so the concatenated chunks are the encoding of the whole stream
+    /// only when `chunk_bytes` is a multiple of `3`.
+    ///
+    /// panics if `chunk_bytes` is `0`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let chunks: Vec<_> = gen_iter!({
+    ///     for &b in b"hello!!" {
+    ///         yield b;
+    ///     }
+    /// }).base64_chunks(3).collect();
+    ///
+    /// assert_eq!(chunks, ["aGVs", "bG8h", "IQ=="]);
+    /// ```
+    #[inline]
+    pub fn base64_chunks(self, chunk_bytes: usize) -> Base64Chunks<T> {
+        assert!(chunk_bytes > 0, "base64_chunks needs a positive chunk size");
+        Base64Chunks {
+            iter: self,
+            chunk_bytes,
+            // grown by the first chunk and reused, `chunk_bytes` may be huge
+            buf: Vec::new(),
+        }
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let b = [group[0], *group.get(1).unwrap_or(&0), *group.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= group.len() {
+                s.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+    s
+}
+
+impl<T> Iterator for Base64Chunks<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buf.len() < self.chunk_bytes {
+            match self.iter.next() {
+                Some(b) => self.buf.push(b),
+                None => break,
+            }
+        }
+        if self.buf.is_empty() {
+            return None;
+        }
+        let s = encode(&self.buf);
+        self.buf.clear();
+        Some(s)
+    }
+}
+
+impl<T> FusedIterator for Base64Chunks<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use super::{encode, ALPHABET};
+
+    fn decode(s: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        for quad in s.as_bytes().chunks(4) {
+            let mut n = 0u32;
+            let mut len = 0;
+            for &c in quad {
+                n <<= 6;
+                if c != b'=' {
+                    n |= ALPHABET.iter().position(|&a| a == c).unwrap() as u32;
+                    len += 1;
+                }
+            }
+            let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+            out.extend_from_slice(&bytes[..len - 1]);
+        }
+        out
+    }
+
+    #[test]
+    fn encode_padding() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn round_trip() {
+        let g = gen_iter!({
+            for b in 0..=255u8 {
+                yield b.wrapping_mul(7);
+            }
+        });
+
+        let encoded: String = g.base64_chunks(6).collect();
+        let expected: Vec<u8> = (0..=255u8).map(|b| b.wrapping_mul(7)).collect();
+        assert_eq!(decode(&encoded), expected);
+    }
+
+    #[test]
+    fn final_chunk() {
+        let g = gen_iter!({
+            for &b in b"abcde" {
+                yield b;
+            }
+        });
+
+        assert!(g.base64_chunks(4).eq(["YWJjZA==", "ZQ=="].iter().cloned()));
+    }
+
+    #[test]
+    fn empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield 0;
+            }
+        }).base64_chunks(3);
+
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn huge_chunk() {
+        let g = gen_iter!({
+            for &b in b"foo" {
+                yield b;
+            }
+        });
+
+        assert!(g.base64_chunks(usize::MAX).eq(["Zm9v"].iter().cloned()));
+    }
+}
@@ -0,0 +1,131 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::any::Any;
+use std::boxed::Box;
+use std::panic::{self, AssertUnwindSafe};
+
+use GenIter;
+
+/// an iterator catching the panics of the generator,
+/// created by [`GenIter::catch_unwind`].
+#[derive(Copy, Clone, Debug)]
+pub struct CatchUnwindGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    poisoned: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `Ok` items, or an `Err` with the payload of a panic of the generator,
+    /// then end the iteration without resuming the panicked generator again,
+    /// see [`CatchUnwindGenIter::poisoned`].
+    ///
+    /// the panic hook still runs, so the panic message is printed as usual.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 1;
+    ///     panic!("boom");
+    /// }).catch_unwind();
+    ///
+    /// assert_eq!(g.next().unwrap().ok(), Some(1));
+    /// assert!(g.next().unwrap().is_err());
+    /// assert!(g.next().is_none());
+    /// assert!(g.poisoned());
+    /// ```
+    #[inline]
+    pub fn catch_unwind(self) -> CatchUnwindGenIter<T> {
+        CatchUnwindGenIter {
+            iter: self,
+            poisoned: false,
+        }
+    }
+}
+
+impl<T> CatchUnwindGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// whether a panic of the generator has been caught
+    #[inline]
+    pub fn poisoned(&self) -> bool {
+        self.poisoned
+    }
+}
+
+impl<T> Iterator for CatchUnwindGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = Result<T::Yield, Box<dyn Any + Send>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.poisoned {
+            return None;
+        }
+        let iter = &mut self.iter;
+        match panic::catch_unwind(AssertUnwindSafe(|| iter.next())) {
+            Ok(y) => y.map(Ok),
+            Err(e) => {
+                self.poisoned = true;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl<T> FusedIterator for CatchUnwindGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    #[test]
+    fn panic_mid_iteration() {
+        let resumes = Cell::new(0);
+        let mut g = gen_iter!({
+            resumes.set(resumes.get() + 1);
+            yield 1;
+            resumes.set(resumes.get() + 1);
+            if resumes.get() == 2 {
+                panic!("boom");
+            }
+            yield 2;
+        }).catch_unwind();
+
+        assert_eq!(g.next().map(|r| r.ok()), Some(Some(1)));
+        assert!(!g.poisoned());
+
+        let payload = g.next().unwrap().err().unwrap();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+        assert!(g.poisoned());
+
+        assert!(g.next().is_none());
+        assert!(g.next().is_none());
+        assert_eq!(resumes.get(), 2);
+    }
+
+    #[test]
+    fn no_panic() {
+        let mut g = gen_iter!({
+            yield 'a';
+        }).catch_unwind();
+
+        assert_eq!(g.next().map(|r| r.ok()), Some(Some('a')));
+        assert!(g.next().is_none());
+        assert!(!g.poisoned());
+    }
+}
@@ -0,0 +1,138 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator calling a checkpoint callback every `n` yields,
+/// created by [`GenIter::checkpoint_every`].
+#[derive(Copy, Clone, Debug)]
+pub struct CheckpointEvery<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    n: usize,
+    save: F,
+    count: usize,
+    since_save: usize,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// call `save` with the number of items yielded so far after every `n` yields,
+    /// to persist the progress of a long job.
+    ///
+    /// to resume after a crash, skip the saved number of items of a new generator with `nth`.
+    ///
+    /// panics if `n` is `0`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut saved = Vec::new();
+    /// let g = gen_iter!({
+    ///     for i in 0..7 {
+    ///         yield i;
+    ///     }
+    /// }).checkpoint_every(3, |done| saved.push(done));
+    ///
+    /// assert_eq!(g.count(), 7);
+    /// assert_eq!(saved, [3, 6]);
+    /// ```
+    #[inline]
+    pub fn checkpoint_every<F>(self, n: usize, save: F) -> CheckpointEvery<T, F>
+    where
+        F: FnMut(usize),
+    {
+        assert!(n > 0, "checkpoint_every needs a positive interval");
+        CheckpointEvery {
+            iter: self,
+            n,
+            save,
+            count: 0,
+            since_save: 0,
+        }
+    }
+}
+
+impl<T, F> Iterator for CheckpointEvery<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    F: FnMut(usize),
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        self.count += 1;
+        self.since_save += 1;
+        if self.since_save == self.n {
+            self.since_save = 0;
+            (self.save)(self.count);
+        }
+        Some(y)
+    }
+}
+
+impl<T, F> FusedIterator for CheckpointEvery<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    F: FnMut(usize),
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn checkpoints() {
+        let last = Cell::new(0);
+        let calls = Cell::new(0);
+        let g = gen_iter!({
+            for i in 0..25 {
+                yield i;
+            }
+        }).checkpoint_every(10, |done| {
+            assert_eq!(done, last.get() + 10);
+            last.set(done);
+            calls.set(calls.get() + 1);
+        });
+
+        assert!(g.eq(0..25));
+        assert_eq!(last.get(), 20);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn resume_from_checkpoint() {
+        let saved = Cell::new(0);
+        let mut g = gen_iter!({
+            for i in 0..10 {
+                yield i;
+            }
+        }).checkpoint_every(4, |done| saved.set(done));
+        g.by_ref().take(6).for_each(drop);
+        assert_eq!(saved.get(), 4);
+
+        let mut restarted = gen_iter!({
+            for i in 0..10 {
+                yield i;
+            }
+        });
+        assert_eq!(restarted.nth(saved.get()), Some(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_interval() {
+        gen_iter!({
+            yield 1;
+        }).checkpoint_every(0, |_| ());
+    }
+}
@@ -0,0 +1,163 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding each run of equal yields once with its length,
+/// created by [`GenIter::collapse_repeats`].
+#[derive(Copy, Clone, Debug)]
+pub struct CollapseRepeats<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    run: Option<(T::Yield, usize)>,
+}
+
+/// the iterator created by [`GenIter::dedup_with_count`], the same as [`CollapseRepeats`]
+pub type DedupWithCount<T> = CollapseRepeats<T>;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq,
+{
+    /// yield `(value, run_length)` for each run of equal yields,
+    /// flushed when a different value is yielded or the generator completes.
+    ///
+    /// only the current value and its count are buffered.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield "connected";
+    ///     yield "timeout";
+    ///     yield "timeout";
+    ///     yield "timeout";
+    /// }).collapse_repeats();
+    ///
+    /// assert_eq!(g.next(), Some(("connected", 1)));
+    /// assert_eq!(g.next(), Some(("timeout", 3)));
+    /// assert_eq!(g.next(), None);
+    /// ```
+    #[inline]
+    pub fn collapse_repeats(self) -> CollapseRepeats<T> {
+        CollapseRepeats {
+            iter: self,
+            run: None,
+        }
+    }
+
+    /// yield `(value, count)` for each run of equal yields, the same as [`GenIter::collapse_repeats`].
+    ///
+    /// unlike `itertools::Itertools::dedup_with_count`, the count comes second.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let runs: Vec<_> = gen_iter!({
+    ///     for c in "aaabcc".chars() {
+    ///         yield c;
+    ///     }
+    /// }).dedup_with_count().collect();
+    ///
+    /// assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2)]);
+    /// ```
+    #[inline]
+    pub fn dedup_with_count(self) -> DedupWithCount<T> {
+        self.collapse_repeats()
+    }
+}
+
+impl<T> Iterator for CollapseRepeats<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq,
+{
+    type Item = (T::Yield, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for y in &mut self.iter {
+            match self.run {
+                Some((ref value, ref mut len)) if *value == y => *len += 1,
+                _ => {
+                    if let Some(run) = self.run.replace((y, 1)) {
+                        return Some(run);
+                    }
+                }
+            }
+        }
+        self.run.take()
+    }
+}
+
+impl<T> FusedIterator for CollapseRepeats<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn long_run_flushed_at_end() {
+        let g = gen_iter!({
+            yield 1;
+            yield 2;
+            yield 2;
+            yield 1;
+            for _ in 0..1000 {
+                yield 3;
+            }
+        }).collapse_repeats();
+
+        assert!(g.eq([(1, 1), (2, 2), (1, 1), (3, 1000)].iter().cloned()));
+    }
+
+    #[test]
+    fn dedup_with_count() {
+        let g = gen_iter!({
+            for &(v, n) in &[(5, 1), (7, 4), (5, 2), (0, 3)] {
+                for _ in 0..n {
+                    yield v;
+                }
+            }
+        }).dedup_with_count();
+
+        assert!(g.eq([(5, 1), (7, 4), (5, 2), (0, 3)].iter().cloned()));
+    }
+
+    #[test]
+    fn empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield 0;
+            }
+        }).collapse_repeats();
+
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn not_clone() {
+        #[derive(Debug, PartialEq)]
+        struct Event(u8);
+
+        let mut g = gen_iter!({
+            yield Event(1);
+            yield Event(1);
+            yield Event(2);
+        }).collapse_repeats();
+
+        assert_eq!(g.next(), Some((Event(1), 2)));
+        assert_eq!(g.next(), Some((Event(2), 1)));
+        assert_eq!(g.next(), None);
+    }
+}
@@ -0,0 +1,141 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator over consecutive pairs of yields, closed by the pair of the
+/// last and the first yield, created by [`GenIter::cyclic_pairs`].
+#[derive(Copy, Clone, Debug)]
+pub struct CyclicPairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    first: Option<T::Yield>,
+    prev: Option<T::Yield>,
+    paired: bool,
+    done: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{
+    /// yield `(x0, x1), (x1, x2), ..., (xn, x0)`, including the wrap-around closing pair.
+    ///
+    /// a generator with less than two yields has no pairs, so nothing is yielded.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let pairs = gen_iter!({
+    ///     yield 'a';
+    ///     yield 'b';
+    ///     yield 'c';
+    /// }).cyclic_pairs();
+    ///
+    /// assert_eq!(pairs.collect::<Vec<_>>(), [('a', 'b'), ('b', 'c'), ('c', 'a')]);
+    /// ```
+    #[inline]
+    pub fn cyclic_pairs(self) -> CyclicPairs<T> {
+        CyclicPairs {
+            iter: self,
+            first: None,
+            prev: None,
+            paired: false,
+            done: false,
+        }
+    }
+}
+
+impl<T> Iterator for CyclicPairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{
+    type Item = (T::Yield, T::Yield);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.prev.is_none() {
+            match self.iter.next() {
+                Some(y) => {
+                    self.first = Some(y.clone());
+                    self.prev = Some(y);
+                },
+                None => {
+                    self.done = true;
+                    return None;
+                },
+            }
+        }
+        match self.iter.next() {
+            Some(y) => {
+                self.paired = true;
+                self.prev.replace(y.clone()).map(|prev| (prev, y))
+            },
+            None => {
+                self.done = true;
+                match (self.prev.take(), self.first.take()) {
+                    (Some(last), Some(first)) if self.paired => Some((last, first)),
+                    _ => None,
+                }
+            },
+        }
+    }
+}
+
+impl<T> FusedIterator for CyclicPairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn closed_triangle() {
+        let mut pairs = gen_iter!({
+            yield 'a';
+            yield 'b';
+            yield 'c';
+        }).cyclic_pairs();
+
+        assert_eq!(pairs.next(), Some(('a', 'b')));
+        assert_eq!(pairs.next(), Some(('b', 'c')));
+        assert_eq!(pairs.next(), Some(('c', 'a')));
+        assert_eq!(pairs.next(), None);
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    fn two_elements() {
+        let pairs = gen_iter!({
+            yield 1;
+            yield 2;
+        }).cyclic_pairs();
+
+        assert!(pairs.eq([(1, 2), (2, 1)].iter().cloned()));
+    }
+
+    #[test]
+    fn less_than_two_elements() {
+        let mut single = gen_iter!({
+            yield 1;
+        }).cyclic_pairs();
+        assert_eq!(single.next(), None);
+
+        let mut empty = gen_iter!({
+            if false {
+                yield 1;
+            }
+        }).cyclic_pairs();
+        assert_eq!(empty.next(), None);
+    }
+}
@@ -0,0 +1,133 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use std::time::Instant;
+
+use GenIter;
+
+/// an iterator that stops resuming the generator after a deadline,
+/// created by [`GenIter::deadline`].
+#[derive(Copy, Clone, Debug)]
+pub struct Deadline<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    at: Instant,
+    timed_out: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// check the clock before each resume, and end the iteration once `at` is passed.
+    ///
+    /// the generator is left suspended, it is not resumed after the deadline.
+    /// once the generator is done, the iteration ends without checking the clock,
+    /// so it is never reported as timed out.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::time::Instant;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     loop {
+    ///         yield 1;
+    ///     }
+    /// }).deadline(Instant::now());
+    ///
+    /// assert_eq!(g.next(), None);
+    /// assert!(g.timed_out());
+    /// ```
+    #[inline]
+    pub fn deadline(self, at: Instant) -> Deadline<T> {
+        Deadline {
+            iter: self,
+            at,
+            timed_out: false,
+        }
+    }
+}
+
+impl<T> Deadline<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// whether the iteration ended because the deadline is passed
+    #[inline]
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+}
+
+impl<T> Iterator for Deadline<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.timed_out || self.iter.done {
+            return None;
+        }
+        if Instant::now() >= self.at {
+            self.timed_out = true;
+            return None;
+        }
+        self.iter.next()
+    }
+}
+
+impl<T> FusedIterator for Deadline<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn far_deadline() {
+        let mut g = gen_iter!({
+            for i in 0..3 {
+                yield i;
+            }
+        }).deadline(Instant::now() + Duration::from_secs(3600));
+
+        assert!(g.by_ref().eq(0..3));
+        assert!(!g.timed_out());
+    }
+
+    #[test]
+    fn done_before_deadline() {
+        let mut inner = gen_iter!({
+            yield 1;
+        });
+        assert_eq!(inner.next(), Some(1));
+        assert_eq!(inner.next(), None);
+
+        let mut g = inner.deadline(Instant::now());
+        assert_eq!(g.next(), None);
+        assert!(!g.timed_out());
+    }
+
+    #[test]
+    fn passed_deadline() {
+        let resumes = Cell::new(0);
+        let mut g = gen_iter!({
+            resumes.set(resumes.get() + 1);
+            yield 1;
+        }).deadline(Instant::now());
+
+        assert_eq!(g.next(), None);
+        assert!(g.timed_out());
+        assert_eq!(g.next(), None);
+        assert_eq!(resumes.get(), 0);
+    }
+}
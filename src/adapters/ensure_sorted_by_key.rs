@@ -0,0 +1,99 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use core::fmt;
+
+use {GenIter, GenIterReturn};
+
+/// the error returned by [`GenIter::ensure_sorted_by_key`],
+/// `index` is the position of the first item whose key is less than the previous key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutOfOrderError {
+    pub index: usize,
+}
+
+impl fmt::Display for OutOfOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "item {} is out of order", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for OutOfOrderError {}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield the items while their keys are non-decreasing.
+    ///
+    /// the returned generator returns `Ok(())` if all the keys are in order,
+    /// or stops at the first out of order item, which is not yielded, and returns its position.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::{gen_iter, OutOfOrderError};
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield (1, 'a');
+    ///     yield (3, 'b');
+    ///     yield (2, 'c');
+    /// }).ensure_sorted_by_key(|&(k, _)| k);
+    ///
+    /// assert_eq!((&mut g).collect::<Vec<_>>(), [(1, 'a'), (3, 'b')]);
+    /// assert_eq!(g.return_or_self().ok(), Some(Err(OutOfOrderError { index: 2 })));
+    /// ```
+    pub fn ensure_sorted_by_key<K, F>(self, mut key: F) -> GenIterReturn<impl Generator<Yield = T::Yield, Return = Result<(), OutOfOrderError>> + Unpin>
+    where
+        K: Ord,
+        F: FnMut(&T::Yield) -> K,
+    {
+        GenIterReturn::new(move || {
+            let mut prev = None;
+            for (index, y) in self.enumerate() {
+                let k = key(&y);
+                if let Some(ref p) = prev {
+                    if k < *p {
+                        return Err(OutOfOrderError { index });
+                    }
+                }
+                prev = Some(k);
+                yield y;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OutOfOrderError;
+
+    #[test]
+    fn in_order() {
+        let mut g = gen_iter!({
+            for &k in &[1, 2, 2, 5] {
+                yield k;
+            }
+        }).ensure_sorted_by_key(|&k| k);
+
+        assert!((&mut g).eq([1, 2, 2, 5].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(Ok(())));
+    }
+
+    #[test]
+    fn descending_key() {
+        let mut g = gen_iter!({
+            for &k in &[1, 4, 3, 5] {
+                yield k;
+            }
+        }).ensure_sorted_by_key(|&k| k);
+
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!((&mut g).next(), Some(4));
+        assert_eq!((&mut g).next(), None);
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some(Err(OutOfOrderError { index: 2 })));
+    }
+}
@@ -0,0 +1,122 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use GenIter;
+
+/// an iterator that only resumes the generator while a flag is set,
+/// created by [`GenIter::gated`].
+#[derive(Clone, Debug)]
+pub struct Gated<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    flag: Arc<AtomicBool>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// wait in `next()` until `flag` is `true` before resuming the generator,
+    /// so another thread can pause and resume the production.
+    ///
+    /// the waiting thread gives up its time slice with `std::thread::yield_now`,
+    /// `next()` returns `None` without waiting once the generator is done.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::AtomicBool;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let flag = Arc::new(AtomicBool::new(true));
+    /// let mut g = gen_iter!({ yield 1; }).gated(flag.clone());
+    /// assert_eq!(g.next(), Some(1));
+    /// ```
+    #[inline]
+    pub fn gated(self, flag: Arc<AtomicBool>) -> Gated<T> {
+        Gated {
+            iter: self,
+            flag,
+        }
+    }
+}
+
+impl<T> Iterator for Gated<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.done {
+            return None;
+        }
+        while !self.flag.load(Ordering::Acquire) {
+            thread::yield_now();
+        }
+        self.iter.next()
+    }
+}
+
+impl<T> FusedIterator for Gated<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn flag_controls_next() {
+        let flag = Arc::new(AtomicBool::new(true));
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        }).gated(flag.clone());
+
+        assert_eq!(g.next(), Some(1));
+
+        flag.store(false, Ordering::Release);
+        let delay = Duration::from_millis(50);
+        let start = Instant::now();
+        let opener = {
+            let flag = flag.clone();
+            thread::spawn(move || {
+                thread::sleep(delay);
+                flag.store(true, Ordering::Release);
+            })
+        };
+
+        assert_eq!(g.next(), Some(2));
+        assert!(start.elapsed() >= delay);
+        opener.join().unwrap();
+
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn done_with_flag_cleared() {
+        let flag = Arc::new(AtomicBool::new(true));
+        let mut g = gen_iter!({
+            yield 1;
+        }).gated(flag.clone());
+
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.next(), None);
+
+        flag.store(false, Ordering::Release);
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+    }
+}
@@ -0,0 +1,114 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator over the running integral of `i32` samples in fixed-point,
+/// created by [`GenIter::integrate_fixed`].
+#[derive(Copy, Clone, Debug)]
+pub struct IntegrateFixed<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    shift: u32,
+    acc: i32,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Yield = i32, Return = ()> + Unpin,
+{
+    /// yield the running integral of the samples, with a time step of `1 / 2^shift`.
+    ///
+    /// the sum is kept in fixed-point with `shift` fractional bits, so no precision is lost between samples,
+    /// and each yield is its integer part, `sum >> shift`, rounded toward negative infinity.
+    /// the sum saturates at the `i32` bounds instead of overflowing.
+    ///
+    /// panics if `shift` is `32` or more.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// // 1.5 per sample with 2 fractional bits
+    /// let integral: Vec<_> = gen_iter!({
+    ///     for _ in 0..4 {
+    ///         yield 6;
+    ///     }
+    /// }).integrate_fixed(2).collect();
+    ///
+    /// assert_eq!(integral, [1, 3, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn integrate_fixed(self, shift: u32) -> IntegrateFixed<T> {
+        assert!(shift < 32, "integrate_fixed supports at most 31 fractional bits");
+        IntegrateFixed {
+            iter: self,
+            shift,
+            acc: 0,
+        }
+    }
+}
+
+impl<T> Iterator for IntegrateFixed<T>
+where
+    T: Generator<Yield = i32, Return = ()> + Unpin,
+{
+    type Item = i32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.iter.next()?;
+        self.acc = self.acc.saturating_add(sample);
+        Some(self.acc >> self.shift)
+    }
+}
+
+impl<T> FusedIterator for IntegrateFixed<T>
+where
+    T: Generator<Yield = i32, Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn accumulate() {
+        let g = gen_iter!({
+            for &s in &[16, 8, -4, -40, 20] {
+                yield s;
+            }
+        }).integrate_fixed(4);
+
+        // sums 16, 24, 20, -20, 0 in units of 1/16
+        assert!(g.eq([1, 1, 1, -2, 0].iter().cloned()));
+    }
+
+    #[test]
+    fn no_fraction() {
+        let g = gen_iter!({
+            for s in 1..5 {
+                yield s;
+            }
+        }).integrate_fixed(0);
+
+        assert!(g.eq([1, 3, 6, 10].iter().cloned()));
+    }
+
+    #[test]
+    fn saturate() {
+        let g = gen_iter!({
+            yield i32::MAX - 1;
+            yield 10;
+            yield -1;
+            yield i32::MIN;
+            yield i32::MIN;
+            yield 1;
+        }).integrate_fixed(0);
+
+        let expected = [i32::MAX - 1, i32::MAX, i32::MAX - 1, -2, i32::MIN, i32::MIN + 1];
+        assert!(g.eq(expected.iter().cloned()));
+    }
+}
@@ -0,0 +1,106 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::{Unpin, PhantomData};
+use alloc::borrow::Cow;
+
+use GenIter;
+
+/// an iterator converting the yields to `Cow<'a, str>`,
+/// created by [`GenIter::into_cow`].
+#[derive(Copy, Clone, Debug)]
+pub struct IntoCow<'a, T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    _cow: PhantomData<Cow<'a, str>>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `Cow<'a, str>`, borrowed for `&'a str` yields and owned for `String` yields,
+    /// so string generators of both kinds can be used by the same pipeline.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::borrow::Cow;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield "borrowed";
+    /// }).into_cow();
+    ///
+    /// assert!(matches!(g.next(), Some(Cow::Borrowed("borrowed"))));
+    /// ```
+    #[inline]
+    pub fn into_cow<'a>(self) -> IntoCow<'a, T>
+    where
+        T::Yield: Into<Cow<'a, str>>,
+    {
+        IntoCow {
+            iter: self,
+            _cow: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IntoCow<'a, T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<Cow<'a, str>>,
+{
+    type Item = Cow<'a, str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Into::into)
+    }
+}
+
+impl<'a, T> FusedIterator for IntoCow<'a, T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<Cow<'a, str>>,
+{}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+    use alloc::string::String;
+
+    #[test]
+    fn borrowed() {
+        let text = String::from("a b c");
+        let mut g = gen_iter!({
+            for word in text.split(' ') {
+                yield word;
+            }
+        }).into_cow();
+
+        for expected in &["a", "b", "c"] {
+            match g.next() {
+                Some(Cow::Borrowed(s)) => assert_eq!(s, *expected),
+                other => panic!("expected a borrowed str, got {:?}", other),
+            }
+        }
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn owned() {
+        let mut g = gen_iter!({
+            yield String::from("x");
+            yield String::from("yz");
+        }).into_cow();
+
+        match g.next() {
+            Some(Cow::Owned(s)) => assert_eq!(s, "x"),
+            other => panic!("expected an owned string, got {:?}", other),
+        }
+        assert_eq!(g.next().as_deref(), Some("yz"));
+        assert_eq!(g.next(), None);
+    }
+}
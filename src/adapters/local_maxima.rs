@@ -0,0 +1,117 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding each item with whether it is a local maximum,
+/// created by [`GenIter::local_maxima`].
+#[derive(Copy, Clone, Debug)]
+pub struct LocalMaxima<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    prev: Option<T::Yield>,
+    cur: Option<T::Yield>,
+    started: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{
+    /// yield `(item, is_peak)`, an item is a peak if it is strictly greater than both neighbors.
+    ///
+    /// each item is yielded after its following item has been generated.
+    /// the first and the last items have only one neighbor, so they are never peaks.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let peaks = gen_iter!({
+    ///     for &i in &[1, 3, 2, 4, 1] {
+    ///         yield i;
+    ///     }
+    /// }).local_maxima();
+    ///
+    /// assert_eq!(
+    ///     peaks.collect::<Vec<_>>(),
+    ///     [(1, false), (3, true), (2, false), (4, true), (1, false)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn local_maxima(self) -> LocalMaxima<T> {
+        LocalMaxima {
+            iter: self,
+            prev: None,
+            cur: None,
+            started: false,
+        }
+    }
+}
+
+impl<T> Iterator for LocalMaxima<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{
+    type Item = (T::Yield, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            self.cur = self.iter.next();
+        }
+        let cur = self.cur.take()?;
+        let next = self.iter.next();
+        let is_peak = match (&self.prev, &next) {
+            (Some(prev), Some(next)) => cur > *prev && cur > *next,
+            _ => false,
+        };
+        self.prev = Some(cur.clone());
+        self.cur = next;
+        Some((cur, is_peak))
+    }
+}
+
+impl<T> FusedIterator for LocalMaxima<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn peaks() {
+        let peaks = gen_iter!({
+            for &i in &[1, 3, 2, 4, 1] {
+                yield i;
+            }
+        }).local_maxima();
+
+        let expected = [(1, false), (3, true), (2, false), (4, true), (1, false)];
+        assert!(peaks.eq(expected.iter().cloned()));
+    }
+
+    #[test]
+    fn plateau_and_edges() {
+        let mut peaks = gen_iter!({
+            yield 5;
+            yield 2;
+            yield 2;
+            yield 9;
+        }).local_maxima();
+
+        assert_eq!(peaks.next(), Some((5, false)));
+        assert_eq!(peaks.next(), Some((2, false)));
+        assert_eq!(peaks.next(), Some((2, false)));
+        assert_eq!(peaks.next(), Some((9, false)));
+        assert_eq!(peaks.next(), None);
+        assert_eq!(peaks.next(), None);
+    }
+}
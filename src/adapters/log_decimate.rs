@@ -0,0 +1,98 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator keeping the yields at the indices `0, 1, 3, 7, 15, ...`,
+/// created by [`GenIter::log_decimate`].
+#[derive(Copy, Clone, Debug)]
+pub struct LogDecimate<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    skip: usize,
+    started: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// keep the 1st, 2nd, 4th, 8th, ... yields, that is the indices `2^k - 1`,
+    /// the other yields are discarded.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let kept = gen_iter!({
+    ///     for i in 0..20 {
+    ///         yield i;
+    ///     }
+    /// }).log_decimate();
+    ///
+    /// assert_eq!(kept.collect::<Vec<_>>(), [0, 1, 3, 7, 15]);
+    /// ```
+    #[inline]
+    pub fn log_decimate(self) -> LogDecimate<T> {
+        LogDecimate {
+            iter: self,
+            skip: 0,
+            started: false,
+        }
+    }
+}
+
+impl<T> Iterator for LogDecimate<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.nth(self.skip)?;
+        // after keeping index `i`, the next kept index is `2 * i + 1`, so `i` items are skipped
+        if self.started {
+            self.skip = self.skip.saturating_mul(2).saturating_add(1);
+        }
+        self.started = true;
+        Some(y)
+    }
+}
+
+impl<T> FusedIterator for LogDecimate<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn kept_indices() {
+        let kept = gen_iter!({
+            for i in 0..32 {
+                yield i;
+            }
+        }).log_decimate();
+
+        assert!(kept.eq([0, 1, 3, 7, 15, 31].iter().cloned()));
+    }
+
+    #[test]
+    fn short() {
+        let mut kept = gen_iter!({
+            for i in 0..3 {
+                yield i;
+            }
+        }).log_decimate();
+
+        assert_eq!(kept.next(), Some(0));
+        assert_eq!(kept.next(), Some(1));
+        assert_eq!(kept.next(), None);
+        assert_eq!(kept.next(), None);
+    }
+}
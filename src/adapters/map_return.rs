@@ -0,0 +1,158 @@
+use core::ops::{Generator, GeneratorState};
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::pin::Pin;
+
+use gen_iter_return::{GenIterReturn, State};
+
+/// a [`GenIterReturn`] with its return value mapped by a closure,
+/// created by [`GenIterReturn::map_return`].
+///
+/// like `GenIterReturn`, `&mut MapReturn` is the iterator.
+#[derive(Copy, Clone, Debug)]
+pub struct MapReturn<G: Generator + Unpin, F, R2> {
+    gen: Option<G>,
+    f: Option<F>,
+    ret: Option<R2>,
+}
+
+impl<G: Generator + Unpin> GenIterReturn<G> {
+    /// map the return value with `f` when the generator completes, the yields are kept unchanged.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     return "done";
+    /// }).map_return(|r| r.len());
+    ///
+    /// assert_eq!((&mut g).collect::<Vec<_>>(), [1]);
+    /// assert_eq!(g.return_or_self().ok(), Some(4));
+    /// ```
+    pub fn map_return<R2, F>(self, f: F) -> MapReturn<G, F, R2>
+    where
+        F: FnOnce(G::Return) -> R2,
+    {
+        match self.0 {
+            State::Running(g) => MapReturn {
+                gen: Some(g),
+                f: Some(f),
+                ret: None,
+            },
+            State::Returned(r) => MapReturn {
+                gen: None,
+                f: None,
+                ret: Some(f(r)),
+            },
+            State::Taken => MapReturn {
+                gen: None,
+                f: None,
+                ret: None,
+            },
+        }
+    }
+}
+
+impl<G: Generator + Unpin, F, R2> MapReturn<G, F, R2> {
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.gen.is_none()
+    }
+
+    #[inline]
+    pub fn return_or_self(self) -> Result<R2, Self> {
+        match self.ret {
+            Some(r) => Ok(r),
+            None => Err(self),
+        }
+    }
+}
+
+impl<G, F, R2> Iterator for &mut MapReturn<G, F, R2>
+where
+    G: Generator + Unpin,
+    F: FnOnce(G::Return) -> R2,
+{
+    type Item = G::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = Pin::new(self.gen.as_mut()?).resume(());
+        match state {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(r) => {
+                self.gen = None;
+                self.ret = self.f.take().map(|f| f(r));
+                None
+            },
+        }
+    }
+}
+
+impl<G, F, R2> FusedIterator for &mut MapReturn<G, F, R2>
+where
+    G: Generator + Unpin,
+    F: FnOnce(G::Return) -> R2,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn str_len() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            return "four";
+        }).map_return(str::len);
+
+        assert_eq!((&mut g).next(), Some(1));
+        assert!(!g.is_done());
+        g = match g.return_or_self() {
+            Ok(_) => panic!("generator is done but should not"),
+            Err(g) => g,
+        };
+
+        assert_eq!((&mut g).next(), Some(2));
+        assert_eq!((&mut g).next(), None);
+        assert_eq!((&mut g).next(), None);
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some(4));
+    }
+
+    #[test]
+    fn mapped_once_at_completion() {
+        let calls = Cell::new(0);
+        let mut g = gen_iter_return!({
+            yield ();
+            return 1;
+        }).map_return(|r| {
+            calls.set(calls.get() + 1);
+            r + 1
+        });
+
+        assert_eq!((&mut g).count(), 1);
+        assert_eq!(calls.get(), 1);
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(g.return_or_self().ok(), Some(2));
+    }
+
+    #[test]
+    fn already_done() {
+        let mut g = gen_iter_return!({
+            if false {
+                yield 0;
+            }
+            return 'a';
+        });
+        assert_eq!((&mut g).next(), None);
+
+        let g = g.map_return(|c| c.to_ascii_uppercase());
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some('A'));
+    }
+}
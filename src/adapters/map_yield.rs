@@ -0,0 +1,77 @@
+use core::ops::{Generator, GeneratorState};
+use core::marker::Unpin;
+use core::pin::Pin;
+
+use gen_iter_return::{GenIterReturn, State};
+
+impl<G: Generator + Unpin> GenIterReturn<G> {
+    /// map the yielded values with `f`, the return value is kept unchanged.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     return "done";
+    /// }).map_yield(|y| y * 10);
+    ///
+    /// assert_eq!((&mut g).collect::<Vec<_>>(), [10, 20]);
+    /// assert_eq!(g.return_or_self().ok(), Some("done"));
+    /// ```
+    pub fn map_yield<Y2, F>(self, mut f: F) -> GenIterReturn<impl Generator<Yield = Y2, Return = G::Return> + Unpin>
+    where
+        F: FnMut(G::Yield) -> Y2,
+    {
+        let mut g = match self.0 {
+            State::Running(g) => g,
+            State::Returned(r) => return GenIterReturn(State::Returned(r)),
+            State::Taken => return GenIterReturn(State::Taken),
+        };
+        GenIterReturn::new(move || {
+            loop {
+                match Pin::new(&mut g).resume(()) {
+                    GeneratorState::Yielded(y) => yield f(y),
+                    GeneratorState::Complete(r) => return r,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn u32_to_string_keeps_bool_return() {
+        use alloc::string::{String, ToString};
+
+        let mut g = gen_iter_return!({
+            yield 1u32;
+            yield 23u32;
+            return true;
+        }).map_yield(|y| y.to_string());
+
+        assert_eq!((&mut g).next(), Some(String::from("1")));
+        assert_eq!((&mut g).next(), Some(String::from("23")));
+        assert_eq!((&mut g).next(), None);
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some(true));
+    }
+
+    #[test]
+    fn map_done() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            return "done";
+        });
+        for _ in &mut g {}
+
+        let mut g = g.map_yield(|y| y + 1);
+        assert!(g.is_done());
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+}
@@ -0,0 +1,121 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator that folds each group of consecutive yields into one value,
+/// created by [`GenIter::merge_consecutive`].
+#[derive(Copy, Clone, Debug)]
+pub struct MergeConsecutive<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    window: usize,
+    merge: F,
+    done: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// fold every `window` consecutive yields into one value using `merge`,
+    /// the last group may be smaller.
+    ///
+    /// panics if `window` is `0`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let sums = gen_iter!({
+    ///     for i in 1..7 {
+    ///         yield i;
+    ///     }
+    /// }).merge_consecutive(3, |a, b| a + b);
+    ///
+    /// assert_eq!(sums.collect::<Vec<_>>(), [6, 15]);
+    /// ```
+    #[inline]
+    pub fn merge_consecutive<F>(self, window: usize, merge: F) -> MergeConsecutive<T, F>
+    where
+        F: FnMut(T::Yield, T::Yield) -> T::Yield,
+    {
+        assert!(window != 0, "window must be greater than 0");
+        MergeConsecutive {
+            iter: self,
+            window,
+            merge,
+            done: false,
+        }
+    }
+}
+
+impl<T, F> Iterator for MergeConsecutive<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    F: FnMut(T::Yield, T::Yield) -> T::Yield,
+{
+    type Item = T::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut acc = match self.iter.next() {
+            Some(y) => y,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        for _ in 1..self.window {
+            match self.iter.next() {
+                Some(y) => acc = (self.merge)(acc, y),
+                None => {
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+        Some(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn sum_groups() {
+        let sums = gen_iter!({
+            for i in 1..7 {
+                yield i;
+            }
+        }).merge_consecutive(3, |a, b| a + b);
+
+        assert!(sums.eq([6, 15].iter().cloned()));
+    }
+
+    #[test]
+    fn smaller_last_group() {
+        let mut sums = gen_iter!({
+            for i in 1..6 {
+                yield i;
+            }
+        }).merge_consecutive(2, |a, b| a + b);
+
+        assert_eq!(sums.next(), Some(3));
+        assert_eq!(sums.next(), Some(7));
+        assert_eq!(sums.next(), Some(5));
+        assert_eq!(sums.next(), None);
+        assert_eq!(sums.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_window() {
+        gen_iter!({ yield 1; }).merge_consecutive(0, |a, b| a + b);
+    }
+}
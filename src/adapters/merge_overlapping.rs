@@ -0,0 +1,116 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator coalescing overlapping intervals,
+/// created by [`GenIter::merge_overlapping`].
+#[derive(Copy, Clone, Debug)]
+pub struct MergeOverlapping<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    current: Option<(i64, i64)>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Yield = (i64, i64), Return = ()> + Unpin,
+{
+    /// merge the `(start, end)` intervals yielded in order of `start`,
+    /// coalescing those that overlap or touch, that is when the next start is at most the current end.
+    ///
+    /// only the current merged interval is buffered.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let merged: Vec<_> = gen_iter!({
+    ///     yield (1, 3);
+    ///     yield (2, 5);
+    ///     yield (5, 6);
+    ///     yield (8, 9);
+    /// }).merge_overlapping().collect();
+    ///
+    /// assert_eq!(merged, [(1, 6), (8, 9)]);
+    /// ```
+    #[inline]
+    pub fn merge_overlapping(self) -> MergeOverlapping<T> {
+        MergeOverlapping {
+            iter: self,
+            current: None,
+        }
+    }
+}
+
+impl<T> Iterator for MergeOverlapping<T>
+where
+    T: Generator<Yield = (i64, i64), Return = ()> + Unpin,
+{
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (start, end) in &mut self.iter {
+            match self.current {
+                Some((_, ref mut cur_end)) if start <= *cur_end => {
+                    if end > *cur_end {
+                        *cur_end = end;
+                    }
+                },
+                _ => {
+                    if let Some(merged) = self.current.replace((start, end)) {
+                        return Some(merged);
+                    }
+                },
+            }
+        }
+        self.current.take()
+    }
+}
+
+impl<T> FusedIterator for MergeOverlapping<T>
+where
+    T: Generator<Yield = (i64, i64), Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn merge() {
+        let g = gen_iter!({
+            yield (1, 3);
+            yield (2, 5);
+            yield (7, 9);
+        }).merge_overlapping();
+
+        assert!(g.eq([(1, 5), (7, 9)].iter().cloned()));
+    }
+
+    #[test]
+    fn contained_and_touching() {
+        let g = gen_iter!({
+            yield (-10, 0);
+            yield (-5, -2);
+            yield (0, 1);
+            yield (3, 4);
+            yield (4, 4);
+        }).merge_overlapping();
+
+        assert!(g.eq([(-10, 1), (3, 4)].iter().cloned()));
+    }
+
+    #[test]
+    fn empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield (0, 0);
+            }
+        }).merge_overlapping();
+
+        assert_eq!(g.next(), None);
+    }
+}
@@ -0,0 +1,135 @@
+//! iterator adapters built on top of [`GenIter`](crate::GenIter)
+
+mod utf8_chars;
+pub use self::utf8_chars::*;
+
+mod merge_consecutive;
+pub use self::merge_consecutive::*;
+
+mod with_len;
+pub use self::with_len::*;
+
+mod cyclic_pairs;
+pub use self::cyclic_pairs::*;
+
+mod map_yield;
+
+#[cfg(feature = "std")]
+mod timestamped;
+#[cfg(feature = "std")]
+pub use self::timestamped::*;
+
+mod local_maxima;
+pub use self::local_maxima::*;
+
+mod on_change;
+pub use self::on_change::*;
+
+mod named;
+pub use self::named::*;
+
+#[cfg(feature = "alloc")]
+mod split_on;
+#[cfg(feature = "alloc")]
+pub use self::split_on::*;
+
+mod with_welford;
+pub use self::with_welford::*;
+
+mod tee_fold;
+pub use self::tee_fold::*;
+
+#[cfg(feature = "std")]
+mod gated;
+#[cfg(feature = "std")]
+pub use self::gated::*;
+
+mod log_decimate;
+pub use self::log_decimate::*;
+
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "std")]
+pub use self::deadline::*;
+
+mod prepend;
+pub use self::prepend::*;
+
+mod append;
+pub use self::append::*;
+
+mod prefix_product;
+pub use self::prefix_product::*;
+
+mod require_progress;
+pub use self::require_progress::*;
+
+mod running_gcd;
+pub use self::running_gcd::*;
+
+mod safe;
+pub use self::safe::*;
+
+#[cfg(feature = "alloc")]
+mod into_cow;
+#[cfg(feature = "alloc")]
+pub use self::into_cow::*;
+
+mod with_first_flag;
+pub use self::with_first_flag::*;
+
+mod ensure_sorted_by_key;
+pub use self::ensure_sorted_by_key::*;
+
+#[cfg(feature = "alloc")]
+mod with_running_median;
+#[cfg(feature = "alloc")]
+pub use self::with_running_median::*;
+
+mod poison_on_err;
+pub use self::poison_on_err::*;
+
+mod sorted_join;
+pub use self::sorted_join::*;
+
+mod collapse_repeats;
+pub use self::collapse_repeats::*;
+
+mod with_sequence_ids;
+pub use self::with_sequence_ids::*;
+
+mod map_return;
+pub use self::map_return::*;
+
+mod checkpoint_every;
+pub use self::checkpoint_every::*;
+
+mod merge_overlapping;
+pub use self::merge_overlapping::*;
+
+#[cfg(feature = "alloc")]
+mod base64_chunks;
+#[cfg(feature = "alloc")]
+pub use self::base64_chunks::*;
+
+mod with_high_water;
+pub use self::with_high_water::*;
+
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(feature = "alloc")]
+pub use self::shared::*;
+
+mod watchdog;
+pub use self::watchdog::*;
+
+mod integrate_fixed;
+pub use self::integrate_fixed::*;
+
+mod with_heartbeat;
+pub use self::with_heartbeat::*;
+
+#[cfg(feature = "std")]
+mod catch_unwind;
+#[cfg(feature = "std")]
+pub use self::catch_unwind::*;
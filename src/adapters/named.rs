@@ -0,0 +1,98 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a [`GenIter`] with a name and a count of the yielded items,
+/// created by [`GenIter::named`].
+#[derive(Copy, Clone, Debug)]
+pub struct Named<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    name: &'static str,
+    count: u64,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// attach a name and a yield counter, to be polled by [`Named::stats`]
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// }).named("numbers");
+    ///
+    /// g.next();
+    /// assert_eq!(g.stats(), ("numbers", 1));
+    /// ```
+    #[inline]
+    pub fn named(self, name: &'static str) -> Named<T> {
+        Named {
+            iter: self,
+            name,
+            count: 0,
+        }
+    }
+}
+
+impl<T> Named<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// the name and the number of items yielded so far
+    #[inline]
+    pub fn stats(&self) -> (&'static str, u64) {
+        (self.name, self.count)
+    }
+}
+
+impl<T> Iterator for Named<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        self.count += 1;
+        Some(y)
+    }
+}
+
+impl<T> FusedIterator for Named<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn counts_pulled_items() {
+        let mut g = gen_iter!({
+            for i in 0..3 {
+                yield i;
+            }
+        }).named("counter");
+        assert_eq!(g.stats(), ("counter", 0));
+
+        g.next();
+        g.next();
+        assert_eq!(g.stats(), ("counter", 2));
+
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+        assert_eq!(g.stats(), ("counter", 3));
+    }
+}
@@ -0,0 +1,169 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding the state transitions of a generator,
+/// created by [`GenIter::on_change`].
+#[derive(Copy, Clone, Debug)]
+pub struct OnChange<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    last: Option<T::Yield>,
+}
+
+/// an iterator yielding the state transitions of a generator as `(old, new)` pairs,
+/// created by [`GenIter::on_change_pairs`].
+#[derive(Copy, Clone, Debug)]
+pub struct OnChangePairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    last: Option<T::Yield>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq + Clone,
+{
+    /// yield the first value, then only the values different from the previously yielded one
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let states = gen_iter!({
+    ///     for &s in &["idle", "idle", "busy", "busy", "idle"] {
+    ///         yield s;
+    ///     }
+    /// }).on_change();
+    ///
+    /// assert_eq!(states.collect::<Vec<_>>(), ["idle", "busy", "idle"]);
+    /// ```
+    #[inline]
+    pub fn on_change(self) -> OnChange<T> {
+        OnChange {
+            iter: self,
+            last: None,
+        }
+    }
+
+    /// yield `(old, new)` for each change of the value,
+    /// the first value is not a transition.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let transitions = gen_iter!({
+    ///     for &s in &["idle", "idle", "busy", "busy", "idle"] {
+    ///         yield s;
+    ///     }
+    /// }).on_change_pairs();
+    ///
+    /// assert_eq!(transitions.collect::<Vec<_>>(), [("idle", "busy"), ("busy", "idle")]);
+    /// ```
+    #[inline]
+    pub fn on_change_pairs(self) -> OnChangePairs<T> {
+        OnChangePairs {
+            iter: self,
+            last: None,
+        }
+    }
+}
+
+impl<T> Iterator for OnChange<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq + Clone,
+{
+    type Item = T::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let y = self.iter.next()?;
+            if self.last.as_ref() != Some(&y) {
+                self.last = Some(y.clone());
+                return Some(y);
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for OnChange<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq + Clone,
+{}
+
+impl<T> Iterator for OnChangePairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq + Clone,
+{
+    type Item = (T::Yield, T::Yield);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let new = self.iter.next()?;
+            match self.last {
+                Some(ref old) if *old == new => {},
+                Some(_) => return self.last.replace(new.clone()).map(|old| (old, new)),
+                None => self.last = Some(new),
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for OnChangePairs<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialEq + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn on_change() {
+        let mut states = gen_iter!({
+            for &s in &[0, 0, 1, 1, 1, 2, 0, 0] {
+                yield s;
+            }
+        }).on_change();
+
+        assert_eq!(states.next(), Some(0));
+        assert_eq!(states.next(), Some(1));
+        assert_eq!(states.next(), Some(2));
+        assert_eq!(states.next(), Some(0));
+        assert_eq!(states.next(), None);
+        assert_eq!(states.next(), None);
+    }
+
+    #[test]
+    fn on_change_pairs() {
+        let transitions = gen_iter!({
+            for &s in &[0, 0, 1, 1, 1, 2, 0, 0] {
+                yield s;
+            }
+        }).on_change_pairs();
+
+        assert!(transitions.eq([(0, 1), (1, 2), (2, 0)].iter().cloned()));
+    }
+
+    #[test]
+    fn no_transition() {
+        let mut transitions = gen_iter!({
+            yield 'a';
+            yield 'a';
+        }).on_change_pairs();
+
+        assert_eq!(transitions.next(), None);
+    }
+}
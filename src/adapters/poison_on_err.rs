@@ -0,0 +1,117 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator ending after the first `Err` yield,
+/// created by [`GenIter::poison_on_err`].
+#[derive(Copy, Clone, Debug)]
+pub struct PoisonOnErr<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    poisoned: bool,
+}
+
+impl<T, V, E> GenIter<T>
+where
+    T: Generator<Yield = Result<V, E>, Return = ()> + Unpin,
+{
+    /// yield the `Ok` items and the first `Err`, then end the iteration
+    /// without resuming the generator again, see [`PoisonOnErr::poisoned`].
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield Ok(1);
+    ///     yield Err("bad");
+    ///     yield Ok(2);
+    /// }).poison_on_err();
+    ///
+    /// assert_eq!(g.next(), Some(Ok(1)));
+    /// assert_eq!(g.next(), Some(Err("bad")));
+    /// assert_eq!(g.next(), None);
+    /// assert!(g.poisoned());
+    /// ```
+    #[inline]
+    pub fn poison_on_err(self) -> PoisonOnErr<T> {
+        PoisonOnErr {
+            iter: self,
+            poisoned: false,
+        }
+    }
+}
+
+impl<T> PoisonOnErr<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// whether an `Err` has been yielded
+    #[inline]
+    pub fn poisoned(&self) -> bool {
+        self.poisoned
+    }
+}
+
+impl<T, V, E> Iterator for PoisonOnErr<T>
+where
+    T: Generator<Yield = Result<V, E>, Return = ()> + Unpin,
+{
+    type Item = Result<V, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.poisoned {
+            return None;
+        }
+        let y = self.iter.next()?;
+        self.poisoned = y.is_err();
+        Some(y)
+    }
+}
+
+impl<T, V, E> FusedIterator for PoisonOnErr<T>
+where
+    T: Generator<Yield = Result<V, E>, Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn stops_after_err() {
+        let resumes = Cell::new(0);
+        let mut g = gen_iter!({
+            resumes.set(resumes.get() + 1);
+            yield Ok(1);
+            resumes.set(resumes.get() + 1);
+            yield Err(());
+            resumes.set(resumes.get() + 1);
+            yield Ok(2);
+        }).poison_on_err();
+
+        assert!(!g.poisoned());
+        assert_eq!(g.next(), Some(Ok(1)));
+        assert_eq!(g.next(), Some(Err(())));
+        assert!(g.poisoned());
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+        assert_eq!(resumes.get(), 2);
+    }
+
+    #[test]
+    fn all_ok() {
+        let mut g = gen_iter!({
+            yield Ok::<_, ()>(1);
+            yield Ok(2);
+        }).poison_on_err();
+
+        assert!((&mut g).eq([Ok(1), Ok(2)].iter().cloned()));
+        assert!(!g.poisoned());
+    }
+}
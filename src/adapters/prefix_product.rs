@@ -0,0 +1,96 @@
+use core::ops::{Generator, Mul};
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding the running product of the yields,
+/// created by [`GenIter::prefix_product`].
+#[derive(Copy, Clone, Debug)]
+pub struct PrefixProduct<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    acc: Option<T::Yield>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Mul<Output = T::Yield> + Clone,
+{
+    /// yield the product of all items so far, the first output is the first item
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let factorials = gen_iter!({
+    ///     for i in 1..=5 {
+    ///         yield i;
+    ///     }
+    /// }).prefix_product();
+    ///
+    /// assert_eq!(factorials.collect::<Vec<_>>(), [1, 2, 6, 24, 120]);
+    /// ```
+    #[inline]
+    pub fn prefix_product(self) -> PrefixProduct<T> {
+        PrefixProduct {
+            iter: self,
+            acc: None,
+        }
+    }
+}
+
+impl<T> Iterator for PrefixProduct<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Mul<Output = T::Yield> + Clone,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        let acc = match self.acc.take() {
+            Some(acc) => acc * y,
+            None => y,
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+}
+
+impl<T> FusedIterator for PrefixProduct<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Mul<Output = T::Yield> + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn running_product() {
+        let products = gen_iter!({
+            for &i in &[1, 2, 3, 4] {
+                yield i;
+            }
+        }).prefix_product();
+
+        assert!(products.eq([1, 2, 6, 24].iter().cloned()));
+    }
+
+    #[test]
+    fn floats() {
+        let mut products = gen_iter!({
+            yield 0.5;
+            yield 4.0;
+        }).prefix_product();
+
+        assert_eq!(products.next(), Some(0.5));
+        assert_eq!(products.next(), Some(2.0));
+        assert_eq!(products.next(), None);
+    }
+}
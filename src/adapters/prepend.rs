@@ -0,0 +1,161 @@
+use core::ops::Generator;
+use core::iter::{Iterator, IntoIterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding a value before the yields of a generator,
+/// created by [`GenIter::prepend`].
+#[derive(Copy, Clone, Debug)]
+pub struct Prepend<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    head: Option<T::Yield>,
+    iter: GenIter<T>,
+}
+
+/// an iterator yielding the values of an iterator before the yields of a generator,
+/// created by [`GenIter::prepend_many`].
+#[derive(Copy, Clone, Debug)]
+pub struct PrependMany<T, I>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    head: Option<I>,
+    iter: GenIter<T>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `value` first, then the yields of the generator
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// }).prepend(0);
+    ///
+    /// assert_eq!(g.collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn prepend(self, value: T::Yield) -> Prepend<T> {
+        Prepend {
+            head: Some(value),
+            iter: self,
+        }
+    }
+
+    /// yield the values of `values` first, then the yields of the generator
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     yield 3;
+    /// }).prepend_many(vec![1, 2]);
+    ///
+    /// assert_eq!(g.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn prepend_many<I>(self, values: I) -> PrependMany<T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T::Yield>,
+    {
+        PrependMany {
+            head: Some(values.into_iter()),
+            iter: self,
+        }
+    }
+}
+
+impl<T> Iterator for Prepend<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.head.take() {
+            Some(v) => Some(v),
+            None => self.iter.next(),
+        }
+    }
+}
+
+impl<T> FusedIterator for Prepend<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+impl<T, I> Iterator for PrependMany<T, I>
+where
+    T: Generator<Return = ()> + Unpin,
+    I: Iterator<Item = T::Yield>,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut head) = self.head {
+            match head.next() {
+                Some(v) => return Some(v),
+                None => self.head = None,
+            }
+        }
+        self.iter.next()
+    }
+}
+
+impl<T, I> FusedIterator for PrependMany<T, I>
+where
+    T: Generator<Return = ()> + Unpin,
+    I: Iterator<Item = T::Yield>,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn prepend_one() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        }).prepend(0);
+
+        assert_eq!(g.next(), Some(0));
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn prepend_many() {
+        let g = gen_iter!({
+            yield 3;
+            yield 4;
+        }).prepend_many(0..3);
+
+        assert!(g.eq(0..5));
+    }
+
+    #[test]
+    fn prepend_to_empty() {
+        let g = gen_iter!({
+            if false {
+                yield 0;
+            }
+        }).prepend_many([7, 8].iter().cloned());
+
+        assert!(g.eq([7, 8].iter().cloned()));
+    }
+}
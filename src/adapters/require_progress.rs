@@ -0,0 +1,136 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a filtering iterator that gives up when too many yields in a row are rejected,
+/// created by [`GenIter::require_progress`].
+#[derive(Copy, Clone, Debug)]
+pub struct RequireProgress<T, P>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    predicate: P,
+    max_silent_resumes: usize,
+    stalled: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield the items matching `predicate`, like `Iterator::filter`,
+    /// but end the iteration if more than `max_silent_resumes` resumes in a row
+    /// produce no output, see [`RequireProgress::stalled`].
+    ///
+    /// the generator is left suspended after a stall.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut evens = gen_iter!({
+    ///     yield 2;
+    ///     loop {
+    ///         yield 1;
+    ///     }
+    /// }).require_progress(100, |&i| i % 2 == 0);
+    ///
+    /// assert_eq!(evens.next(), Some(2));
+    /// assert_eq!(evens.next(), None);
+    /// assert!(evens.stalled());
+    /// ```
+    #[inline]
+    pub fn require_progress<P>(self, max_silent_resumes: usize, predicate: P) -> RequireProgress<T, P>
+    where
+        P: FnMut(&T::Yield) -> bool,
+    {
+        RequireProgress {
+            iter: self,
+            predicate,
+            max_silent_resumes,
+            stalled: false,
+        }
+    }
+}
+
+impl<T, P> RequireProgress<T, P>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// whether the iteration ended because of too many rejected yields
+    #[inline]
+    pub fn stalled(&self) -> bool {
+        self.stalled
+    }
+}
+
+impl<T, P> Iterator for RequireProgress<T, P>
+where
+    T: Generator<Return = ()> + Unpin,
+    P: FnMut(&T::Yield) -> bool,
+{
+    type Item = T::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stalled {
+            return None;
+        }
+        let mut silent = 0;
+        loop {
+            let y = self.iter.next()?;
+            if (self.predicate)(&y) {
+                return Some(y);
+            }
+            silent += 1;
+            if silent > self.max_silent_resumes {
+                self.stalled = true;
+                return None;
+            }
+        }
+    }
+}
+
+impl<T, P> FusedIterator for RequireProgress<T, P>
+where
+    T: Generator<Return = ()> + Unpin,
+    P: FnMut(&T::Yield) -> bool,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn long_rejection_run() {
+        let mut g = gen_iter!({
+            yield 10;
+            for i in 0..5 {
+                yield i;
+            }
+            yield 20;
+        }).require_progress(4, |&i| i >= 10);
+
+        assert_eq!(g.next(), Some(10));
+        assert_eq!(g.next(), None);
+        assert!(g.stalled());
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn rejection_run_within_limit() {
+        let mut g = gen_iter!({
+            yield 10;
+            for i in 0..5 {
+                yield i;
+            }
+            yield 20;
+        }).require_progress(5, |&i| i >= 10);
+
+        assert_eq!(g.next(), Some(10));
+        assert_eq!(g.next(), Some(20));
+        assert_eq!(g.next(), None);
+        assert!(!g.stalled());
+    }
+}
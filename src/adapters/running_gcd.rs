@@ -0,0 +1,105 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding the greatest common divisor of the yields so far,
+/// created by [`GenIter::running_gcd`].
+#[derive(Copy, Clone, Debug)]
+pub struct RunningGcd<T>
+where
+    T: Generator<Yield = u64, Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    gcd: Option<u64>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Yield = u64, Return = ()> + Unpin,
+{
+    /// yield the GCD of all items so far, the first output is the first item.
+    ///
+    /// `gcd(0, n)` is `n`, so zeros do not change the result.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     yield 12;
+    ///     yield 18;
+    ///     yield 24;
+    /// }).running_gcd();
+    ///
+    /// assert_eq!(g.collect::<Vec<_>>(), [12, 6, 6]);
+    /// ```
+    #[inline]
+    pub fn running_gcd(self) -> RunningGcd<T> {
+        RunningGcd {
+            iter: self,
+            gcd: None,
+        }
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl<T> Iterator for RunningGcd<T>
+where
+    T: Generator<Yield = u64, Return = ()> + Unpin,
+{
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        let y = self.iter.next()?;
+        let g = match self.gcd {
+            Some(g) => gcd(g, y),
+            None => y,
+        };
+        self.gcd = Some(g);
+        Some(g)
+    }
+}
+
+impl<T> FusedIterator for RunningGcd<T>
+where
+    T: Generator<Yield = u64, Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn gcd_stream() {
+        let g = gen_iter!({
+            yield 12;
+            yield 18;
+            yield 24;
+        }).running_gcd();
+
+        assert!(g.eq([12, 6, 6].iter().cloned()));
+    }
+
+    #[test]
+    fn zeros_and_coprimes() {
+        let g = gen_iter!({
+            yield 0;
+            yield 15;
+            yield 0;
+            yield 10;
+            yield 7;
+        }).running_gcd();
+
+        assert!(g.eq([0, 15, 15, 5, 1].iter().cloned()));
+    }
+}
@@ -0,0 +1,94 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use core::fmt;
+
+use GenIter;
+
+/// the error of [`SafeGenIter::next_checked`] called after the end was reported
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResumedAfterDoneError;
+
+impl fmt::Display for ResumedAfterDoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("generator resumed after the end was reported")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ResumedAfterDoneError {}
+
+/// a strict [`GenIter`] reporting the calls after the end as errors,
+/// created by [`GenIter::safe`].
+#[derive(Copy, Clone, Debug)]
+pub struct SafeGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    ended: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// wrap in a [`SafeGenIter`], to catch the consumers calling `next` after `None`
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::{gen_iter, ResumedAfterDoneError};
+    ///
+    /// let mut g = gen_iter!({ yield 1; }).safe();
+    ///
+    /// assert_eq!(g.next_checked(), Ok(Some(1)));
+    /// assert_eq!(g.next_checked(), Ok(None));
+    /// assert_eq!(g.next_checked(), Err(ResumedAfterDoneError));
+    /// ```
+    #[inline]
+    pub fn safe(self) -> SafeGenIter<T> {
+        SafeGenIter {
+            iter: self,
+            ended: false,
+        }
+    }
+}
+
+impl<T> SafeGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// the next yield, `Ok(None)` once when the generator is done,
+    /// then `Err(ResumedAfterDoneError)` for every later call.
+    ///
+    /// it never panics.
+    #[inline]
+    pub fn next_checked(&mut self) -> Result<Option<T::Yield>, ResumedAfterDoneError> {
+        if self.ended {
+            return Err(ResumedAfterDoneError);
+        }
+        let y = self.iter.next();
+        self.ended = y.is_none();
+        Ok(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResumedAfterDoneError;
+
+    #[test]
+    fn strict_error() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        }).safe();
+
+        assert_eq!(g.next_checked(), Ok(Some(1)));
+        assert_eq!(g.next_checked(), Ok(Some(2)));
+        assert_eq!(g.next_checked(), Ok(None));
+        assert_eq!(g.next_checked(), Err(ResumedAfterDoneError));
+        assert_eq!(g.next_checked(), Err(ResumedAfterDoneError));
+    }
+}
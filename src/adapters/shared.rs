@@ -0,0 +1,116 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::cell::RefCell;
+use alloc::rc::Rc;
+
+use GenIter;
+
+/// a cloneable handle to a [`GenIter`] shared by all its clones,
+/// created by [`GenIter::shared`].
+///
+/// it uses `Rc`, so the handles stay on one thread.
+#[derive(Debug)]
+pub struct SharedGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: Rc<RefCell<GenIter<T>>>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// share the generator between handles, each `next()` on any handle advances it.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut a = gen_iter!({
+    ///     for i in 0..4 {
+    ///         yield i;
+    ///     }
+    /// }).shared();
+    /// let mut b = a.clone();
+    ///
+    /// assert_eq!(a.next(), Some(0));
+    /// assert_eq!(b.next(), Some(1));
+    /// assert_eq!(a.next(), Some(2));
+    /// ```
+    #[inline]
+    pub fn shared(self) -> SharedGenIter<T> {
+        SharedGenIter {
+            iter: Rc::new(RefCell::new(self)),
+        }
+    }
+}
+
+impl<T> Clone for SharedGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// another handle to the same generator
+    #[inline]
+    fn clone(&self) -> Self {
+        SharedGenIter {
+            iter: Rc::clone(&self.iter),
+        }
+    }
+}
+
+impl<T> Iterator for SharedGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    /// panics if called from within the generator through another handle
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.borrow_mut().next()
+    }
+}
+
+impl<T> FusedIterator for SharedGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn interleave() {
+        let mut a = gen_iter!({
+            for i in 0..5 {
+                yield i;
+            }
+        }).shared();
+        let mut b = a.clone();
+
+        assert_eq!(a.next(), Some(0));
+        assert_eq!(b.next(), Some(1));
+        assert_eq!(b.next(), Some(2));
+        assert_eq!(a.next(), Some(3));
+
+        let mut c = b.clone();
+        drop(a);
+        drop(b);
+        assert_eq!(c.next(), Some(4));
+        assert_eq!(c.next(), None);
+    }
+
+    #[test]
+    fn zip_handles() {
+        let a = gen_iter!({
+            for i in 0..6 {
+                yield i;
+            }
+        }).shared();
+        let b = a.clone();
+
+        assert!(a.zip(b).eq([(0, 1), (2, 3), (4, 5)].iter().cloned()));
+    }
+}
@@ -0,0 +1,159 @@
+use core::ops::Generator;
+use core::iter::{Iterator, IntoIterator, FusedIterator};
+use core::marker::Unpin;
+use core::cmp::{Ord, Ordering};
+
+use GenIter;
+
+/// an iterator over the pairs of items with equal keys of two sorted sequences,
+/// created by [`GenIter::sorted_join`].
+#[derive(Clone, Debug)]
+pub struct SortedJoin<T, I, F, G>
+where
+    T: Generator<Return = ()> + Unpin,
+    I: Iterator,
+{
+    left: GenIter<T>,
+    right: I,
+    left_key: F,
+    right_key: G,
+    left_item: Option<T::Yield>,
+    right_item: Option<I::Item>,
+    done: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// merge join the yields with the items of `other`, both sorted by key,
+    /// yielding `(left, right)` for the items with equal keys.
+    ///
+    /// the join is one-to-one, each item is used in at most one pair:
+    /// a key repeated on both sides pairs the repeats in order,
+    /// and the extra repeats on one side are skipped.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let users = gen_iter!({
+    ///     yield (1, "ann");
+    ///     yield (2, "bob");
+    ///     yield (4, "cid");
+    /// });
+    /// let ages = [(1, 30), (3, 25), (4, 41)];
+    ///
+    /// let joined: Vec<_> = users
+    ///     .sorted_join(ages.iter(), |u| u.0, |a| a.0)
+    ///     .map(|(u, a)| (u.1, a.1))
+    ///     .collect();
+    ///
+    /// assert_eq!(joined, [("ann", 30), ("cid", 41)]);
+    /// ```
+    #[inline]
+    pub fn sorted_join<O, K, F, G>(self, other: O, left_key: F, right_key: G) -> SortedJoin<T, O::IntoIter, F, G>
+    where
+        O: IntoIterator,
+        K: Ord,
+        F: FnMut(&T::Yield) -> K,
+        G: FnMut(&O::Item) -> K,
+    {
+        SortedJoin {
+            left: self,
+            right: other.into_iter(),
+            left_key,
+            right_key,
+            left_item: None,
+            right_item: None,
+            done: false,
+        }
+    }
+}
+
+impl<T, I, K, F, G> Iterator for SortedJoin<T, I, F, G>
+where
+    T: Generator<Return = ()> + Unpin,
+    I: Iterator,
+    K: Ord,
+    F: FnMut(&T::Yield) -> K,
+    G: FnMut(&I::Item) -> K,
+{
+    type Item = (T::Yield, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let l = match self.left_item.take().or_else(|| self.left.next()) {
+                Some(l) => l,
+                None => break,
+            };
+            let r = match self.right_item.take().or_else(|| self.right.next()) {
+                Some(r) => r,
+                None => break,
+            };
+            match (self.left_key)(&l).cmp(&(self.right_key)(&r)) {
+                Ordering::Less => self.right_item = Some(r),
+                Ordering::Greater => self.left_item = Some(l),
+                Ordering::Equal => return Some((l, r)),
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+impl<T, I, K, F, G> FusedIterator for SortedJoin<T, I, F, G>
+where
+    T: Generator<Return = ()> + Unpin,
+    I: Iterator,
+    K: Ord,
+    F: FnMut(&T::Yield) -> K,
+    G: FnMut(&I::Item) -> K,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn join() {
+        let left = gen_iter!({
+            for &i in &[1, 3, 5, 7, 9] {
+                yield i;
+            }
+        });
+        let right = [2, 3, 4, 7, 8, 9, 10];
+
+        let joined = left.sorted_join(right.iter().cloned(), |&l| l, |&r| r);
+        assert!(joined.eq([(3, 3), (7, 7), (9, 9)].iter().cloned()));
+    }
+
+    #[test]
+    fn duplicates_pair_in_order() {
+        let left = gen_iter!({
+            yield (1, 'a');
+            yield (1, 'b');
+            yield (1, 'c');
+            yield (2, 'd');
+        });
+        let right = [(1, 'x'), (1, 'y'), (2, 'z')];
+
+        let joined = left
+            .sorted_join(right.iter().cloned(), |l| l.0, |r| r.0)
+            .map(|(l, r)| (l.1, r.1));
+        assert!(joined.eq([('a', 'x'), ('b', 'y'), ('d', 'z')].iter().cloned()));
+    }
+
+    #[test]
+    fn empty_side() {
+        let left = gen_iter!({
+            yield 1;
+        });
+        let mut joined = left.sorted_join(core::iter::empty(), |&l| l, |&r: &i32| r);
+
+        assert_eq!(joined.next(), None);
+        assert_eq!(joined.next(), None);
+    }
+}
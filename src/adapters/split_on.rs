@@ -0,0 +1,120 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// an iterator over groups of yields separated by separator items,
+/// created by [`GenIter::split_on`].
+#[derive(Clone, Debug)]
+pub struct SplitOn<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    is_sep: F,
+    done: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield the groups of items separated by the items matching `is_sep`,
+    /// the separators are not included.
+    ///
+    /// like `str::split`, adjacent separators produce empty groups,
+    /// and the last group is yielded when the generator is done, even if it is empty.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let groups = gen_iter!({
+    ///     for &i in &[1, 2, 0, 3, 0, 0, 4] {
+    ///         yield i;
+    ///     }
+    /// }).split_on(|&i| i == 0);
+    ///
+    /// assert_eq!(groups.collect::<Vec<_>>(), [vec![1, 2], vec![3], vec![], vec![4]]);
+    /// ```
+    #[inline]
+    pub fn split_on<F>(self, is_sep: F) -> SplitOn<T, F>
+    where
+        F: FnMut(&T::Yield) -> bool,
+    {
+        SplitOn {
+            iter: self,
+            is_sep,
+            done: false,
+        }
+    }
+}
+
+impl<T, F> Iterator for SplitOn<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    F: FnMut(&T::Yield) -> bool,
+{
+    type Item = Vec<T::Yield>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut group = Vec::new();
+        loop {
+            match self.iter.next() {
+                Some(y) => {
+                    if (self.is_sep)(&y) {
+                        return Some(group);
+                    }
+                    group.push(y);
+                },
+                None => {
+                    self.done = true;
+                    return Some(group);
+                },
+            }
+        }
+    }
+}
+
+impl<T, F> FusedIterator for SplitOn<T, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    F: FnMut(&T::Yield) -> bool,
+{}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    #[test]
+    fn split_on_zero() {
+        let mut groups = gen_iter!({
+            for &i in &[1, 2, 0, 3, 0, 0, 4, 5] {
+                yield i;
+            }
+        }).split_on(|&i| i == 0);
+
+        assert_eq!(groups.next().unwrap(), [1, 2]);
+        assert_eq!(groups.next().unwrap(), [3]);
+        assert_eq!(groups.next().unwrap(), []);
+        assert_eq!(groups.next().unwrap(), [4, 5]);
+        assert_eq!(groups.next(), None);
+        assert_eq!(groups.next(), None);
+    }
+
+    #[test]
+    fn trailing_separator() {
+        let groups = gen_iter!({
+            yield 1;
+            yield 0;
+        }).split_on(|&i| i == 0);
+
+        assert_eq!(groups.collect::<Vec<_>>(), [vec![1], vec![]]);
+    }
+}
@@ -0,0 +1,118 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIterReturn;
+
+/// a [`GenIterReturn`] folding every yield into an accumulator,
+/// created by [`GenIterReturn::tee_fold`].
+///
+/// `&mut TeeFold<G, B, F>` is an iterator yielding the items unchanged.
+pub struct TeeFold<G: Generator + Unpin, B, F> {
+    inner: GenIterReturn<G>,
+    acc: B,
+    f: F,
+}
+
+impl<G: Generator + Unpin> GenIterReturn<G> {
+    /// fold each yield into `init` with `f` while passing it through
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     return "done";
+    /// }).tee_fold(0, |sum, &y| *sum += y);
+    ///
+    /// assert_eq!((&mut g).collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(g.finish(), (3, Some("done")));
+    /// ```
+    #[inline]
+    pub fn tee_fold<B, F>(self, init: B, f: F) -> TeeFold<G, B, F>
+    where
+        F: FnMut(&mut B, &G::Yield),
+    {
+        TeeFold {
+            inner: self,
+            acc: init,
+            f,
+        }
+    }
+}
+
+impl<G: Generator + Unpin, B, F> TeeFold<G, B, F> {
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.inner.is_done()
+    }
+
+    /// the accumulator so far
+    #[inline]
+    pub fn acc(&self) -> &B {
+        &self.acc
+    }
+
+    /// the accumulator, and the return value if the generator is done
+    #[inline]
+    pub fn finish(self) -> (B, Option<G::Return>) {
+        (self.acc, self.inner.return_or_self().ok())
+    }
+}
+
+impl<G: Generator + Unpin, B, F> Iterator for &mut TeeFold<G, B, F>
+where
+    F: FnMut(&mut B, &G::Yield),
+{
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = (&mut self.inner).next()?;
+        (self.f)(&mut self.acc, &y);
+        Some(y)
+    }
+}
+
+impl<G: Generator + Unpin, B, F> FusedIterator for &mut TeeFold<G, B, F>
+where
+    F: FnMut(&mut B, &G::Yield),
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn acc_and_return() {
+        let mut g = gen_iter_return!({
+            for i in 1..5 {
+                yield i;
+            }
+            return "done";
+        }).tee_fold((0, 0), |acc, &y| {
+            acc.0 += y;
+            acc.1 += 1;
+        });
+
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!(*g.acc(), (1, 1));
+
+        assert!((&mut g).eq(2..5));
+        assert!(g.is_done());
+        assert_eq!(g.finish(), ((10, 4), Some("done")));
+    }
+
+    #[test]
+    fn finish_early() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            return "done";
+        }).tee_fold(0, |sum, &y| *sum += y);
+
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!(g.finish(), (1, None));
+    }
+}
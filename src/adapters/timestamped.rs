@@ -0,0 +1,113 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use std::time::{Duration, Instant};
+
+use GenIter;
+
+/// an iterator stamping each yield with a virtual `Instant`,
+/// created by [`GenIter::timestamped`].
+#[derive(Copy, Clone, Debug)]
+pub struct Timestamped<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    next_at: Option<Instant>,
+    interval: Duration,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `(instant, item)`, the first item is stamped with the current time,
+    /// every following item `interval` after the previous one.
+    ///
+    /// it does not sleep, the timestamps are virtual.
+    /// the iteration stops without resuming the generator
+    /// once the next timestamp cannot be represented by an `Instant`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::time::Duration;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 'a';
+    ///     yield 'b';
+    /// }).timestamped(Duration::from_secs(1));
+    ///
+    /// let (t0, a) = g.next().unwrap();
+    /// let (t1, b) = g.next().unwrap();
+    /// assert_eq!((a, b), ('a', 'b'));
+    /// assert_eq!(t1 - t0, Duration::from_secs(1));
+    /// ```
+    #[inline]
+    pub fn timestamped(self, interval: Duration) -> Timestamped<T> {
+        Timestamped {
+            iter: self,
+            next_at: Some(Instant::now()),
+            interval,
+        }
+    }
+}
+
+impl<T> Iterator for Timestamped<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = (Instant, T::Yield);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let at = self.next_at?;
+        let y = self.iter.next()?;
+        self.next_at = at.checked_add(self.interval);
+        Some((at, y))
+    }
+}
+
+impl<T> FusedIterator for Timestamped<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    #[test]
+    fn exact_interval() {
+        let interval = Duration::from_millis(250);
+        let mut g = gen_iter!({
+            for i in 0..4 {
+                yield i;
+            }
+        }).timestamped(interval);
+
+        let (mut prev, first) = g.next().unwrap();
+        assert_eq!(first, 0);
+        for i in 1..4 {
+            let (at, y) = g.next().unwrap();
+            assert_eq!(y, i);
+            assert_eq!(at - prev, interval);
+            prev = at;
+        }
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn overflow_stops() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        }).timestamped(Duration::MAX);
+
+        assert_eq!(g.next().map(|(_, y)| y), Some(1));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+        assert!(!g.iter.done);
+    }
+}
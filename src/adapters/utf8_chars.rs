@@ -0,0 +1,165 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use core::char::REPLACEMENT_CHARACTER;
+use core::str;
+
+use GenIter;
+
+/// an iterator that decodes the bytes yielded by a generator as UTF-8,
+/// created by [`GenIter::utf8_chars`].
+///
+/// partial multibyte sequences are buffered across yields. every invalid
+/// or truncated sequence is replaced by one `U+FFFD REPLACEMENT CHARACTER`.
+#[derive(Copy, Clone, Debug)]
+pub struct Utf8Chars<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    buf: [u8; 4],
+    len: usize,
+    need: usize,
+    pending: Option<u8>,
+    done: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{
+    /// decode the yielded bytes as UTF-8 `char`s
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let chars = gen_iter!({
+    ///     for &b in "añ€".as_bytes() {
+    ///         yield b;
+    ///     }
+    /// }).utf8_chars();
+    ///
+    /// assert!(chars.eq("añ€".chars()));
+    /// ```
+    #[inline]
+    pub fn utf8_chars(self) -> Utf8Chars<T> {
+        Utf8Chars {
+            iter: self,
+            buf: [0; 4],
+            len: 0,
+            need: 0,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+impl<T> Iterator for Utf8Chars<T>
+where
+    T: Generator<Yield = u8, Return = ()> + Unpin,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let byte = match self.pending.take() {
+                Some(b) => b,
+                None if self.done => return None,
+                None => match self.iter.next() {
+                    Some(b) => b,
+                    None => {
+                        self.done = true;
+                        if self.len == 0 {
+                            return None;
+                        }
+                        // truncated sequence at the end of the stream
+                        self.len = 0;
+                        return Some(REPLACEMENT_CHARACTER);
+                    }
+                },
+            };
+
+            if self.len == 0 {
+                self.need = match byte {
+                    0x00..=0x7F => return Some(byte as char),
+                    0xC2..=0xDF => 2,
+                    0xE0..=0xEF => 3,
+                    0xF0..=0xF4 => 4,
+                    _ => return Some(REPLACEMENT_CHARACTER),
+                };
+                self.buf[0] = byte;
+                self.len = 1;
+                continue;
+            }
+
+            // the second byte is restricted for some leading bytes to reject
+            // overlong encodings, surrogates and values above `U+10FFFF`
+            let (lo, hi) = match (self.len, self.buf[0]) {
+                (1, 0xE0) => (0xA0, 0xBF),
+                (1, 0xED) => (0x80, 0x9F),
+                (1, 0xF0) => (0x90, 0xBF),
+                (1, 0xF4) => (0x80, 0x8F),
+                _ => (0x80, 0xBF),
+            };
+            if byte < lo || byte > hi {
+                // replace the incomplete sequence, then start over with this byte
+                self.len = 0;
+                self.pending = Some(byte);
+                return Some(REPLACEMENT_CHARACTER);
+            }
+
+            self.buf[self.len] = byte;
+            self.len += 1;
+            if self.len == self.need {
+                self.len = 0;
+                return str::from_utf8(&self.buf[..self.need])
+                    .ok()
+                    .and_then(|s| s.chars().next());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::char::REPLACEMENT_CHARACTER as R;
+
+    #[test]
+    fn multibyte_across_yields() {
+        let s = "a\u{e9}\u{20ac}\u{1f600}z";
+        let chars = gen_iter!(move {
+            for &b in s.as_bytes() {
+                yield b;
+            }
+        }).utf8_chars();
+
+        assert!(chars.eq(s.chars()));
+    }
+
+    #[test]
+    fn invalid_sequences() {
+        let mut chars = gen_iter!({
+            yield b'a';
+            yield 0xE2; // `€` is E2 82 AC, cut short by `A`
+            yield 0x82;
+            yield b'A';
+            yield 0xFF; // never valid
+            yield 0xED; // surrogate
+            yield 0xA0;
+            yield 0xF0; // truncated at the end
+            yield 0x9F;
+        }).utf8_chars();
+
+        assert_eq!(chars.next(), Some('a'));
+        assert_eq!(chars.next(), Some(R));
+        assert_eq!(chars.next(), Some('A'));
+        assert_eq!(chars.next(), Some(R));
+        assert_eq!(chars.next(), Some(R));
+        assert_eq!(chars.next(), Some(R));
+        assert_eq!(chars.next(), Some(R));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next(), None);
+    }
+}
@@ -0,0 +1,142 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a filtering iterator reporting the items that took too many resumes to find,
+/// created by [`GenIter::watchdog`].
+#[derive(Copy, Clone, Debug)]
+pub struct Watchdog<T, P, F>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    predicate: P,
+    on_slow: F,
+    warn_after_resumes: usize,
+    resumes: usize,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield the items matching `predicate`, like `Iterator::filter`,
+    /// and call `on_slow(resume_index)` when a `next()` needs more than `warn_after_resumes` resumes.
+    ///
+    /// `resume_index` counts all the resumes from `0`, it is the index of the last resume of the slow `next()`.
+    /// the iteration is not stopped, see [`GenIter::require_progress`] for that.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut slow = Vec::new();
+    /// let evens: Vec<_> = gen_iter!({
+    ///     for &i in &[2, 1, 1, 1, 4, 6] {
+    ///         yield i;
+    ///     }
+    /// }).watchdog(2, |&i| i % 2 == 0, |at| slow.push(at)).collect();
+    ///
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// assert_eq!(slow, [4]);
+    /// ```
+    #[inline]
+    pub fn watchdog<P, F>(self, warn_after_resumes: usize, predicate: P, on_slow: F) -> Watchdog<T, P, F>
+    where
+        P: FnMut(&T::Yield) -> bool,
+        F: FnMut(usize),
+    {
+        Watchdog {
+            iter: self,
+            predicate,
+            on_slow,
+            warn_after_resumes,
+            resumes: 0,
+        }
+    }
+}
+
+impl<T, P, F> Iterator for Watchdog<T, P, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    P: FnMut(&T::Yield) -> bool,
+    F: FnMut(usize),
+{
+    type Item = T::Yield;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut used = 0;
+        let found = loop {
+            let y = self.iter.next();
+            if !self.iter.last_resumed() {
+                break y;
+            }
+            used += 1;
+            self.resumes += 1;
+            match y {
+                Some(y) if !(self.predicate)(&y) => continue,
+                y => break y,
+            }
+        };
+        if used > self.warn_after_resumes {
+            (self.on_slow)(self.resumes - 1);
+        }
+        found
+    }
+}
+
+impl<T, P, F> FusedIterator for Watchdog<T, P, F>
+where
+    T: Generator<Return = ()> + Unpin,
+    P: FnMut(&T::Yield) -> bool,
+    F: FnMut(usize),
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn long_rejection_run() {
+        let warnings = Cell::new(0);
+        let last_at = Cell::new(0);
+        let mut g = gen_iter!({
+            yield 0;
+            for _ in 0..100 {
+                yield 1;
+            }
+            yield 2;
+            yield 4;
+        }).watchdog(10, |&i| i % 2 == 0, |at| {
+            warnings.set(warnings.get() + 1);
+            last_at.set(at);
+        });
+
+        assert_eq!(g.next(), Some(0));
+        assert_eq!(warnings.get(), 0);
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(warnings.get(), 1);
+        assert_eq!(last_at.get(), 101);
+        assert_eq!(g.next(), Some(4));
+        assert_eq!(g.next(), None);
+        assert_eq!(warnings.get(), 1);
+    }
+
+    #[test]
+    fn slow_end() {
+        let warnings = Cell::new(0);
+        let mut g = gen_iter!({
+            for _ in 0..5 {
+                yield 1;
+            }
+        }).watchdog(3, |&i| i == 0, |_| warnings.set(warnings.get() + 1));
+
+        assert_eq!(g.next(), None);
+        assert_eq!(warnings.get(), 1);
+        assert_eq!(g.next(), None);
+        assert_eq!(warnings.get(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding each item with whether it is the first one,
+/// created by [`GenIter::with_first_flag`].
+#[derive(Copy, Clone, Debug)]
+pub struct WithFirstFlag<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    first: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `(item, is_first)`, `is_first` is `true` only for the very first item
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let rows = gen_iter!({
+    ///     yield "name";
+    ///     yield "alice";
+    /// }).with_first_flag();
+    ///
+    /// assert_eq!(rows.collect::<Vec<_>>(), [("name", true), ("alice", false)]);
+    /// ```
+    #[inline]
+    pub fn with_first_flag(self) -> WithFirstFlag<T> {
+        WithFirstFlag {
+            iter: self,
+            first: true,
+        }
+    }
+}
+
+impl<T> Iterator for WithFirstFlag<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = (T::Yield, bool);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        let first = self.first;
+        self.first = false;
+        Some((y, first))
+    }
+}
+
+impl<T> FusedIterator for WithFirstFlag<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn only_first_flagged() {
+        let g = gen_iter!({
+            for i in 0..4 {
+                yield i;
+            }
+        }).with_first_flag();
+
+        assert!(g.eq([(0, true), (1, false), (2, false), (3, false)].iter().cloned()));
+    }
+}
@@ -0,0 +1,125 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator inserting a heartbeat value after every `every` yields,
+/// created by [`GenIter::with_heartbeat`].
+#[derive(Copy, Clone, Debug)]
+pub struct WithHeartbeat<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    every: usize,
+    beat: T::Yield,
+    since_beat: usize,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{
+    /// yield the items with a clone of `beat` inserted after every `every` items.
+    ///
+    /// a beat follows each full group of `every` items, including the last one,
+    /// but no beat follows a last group shorter than `every`.
+    ///
+    /// panics if `every` is `0`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     for i in 1..6 {
+    ///         yield i;
+    ///     }
+    /// }).with_heartbeat(2, 0);
+    ///
+    /// assert_eq!(g.collect::<Vec<_>>(), [1, 2, 0, 3, 4, 0, 5]);
+    /// ```
+    #[inline]
+    pub fn with_heartbeat(self, every: usize, beat: T::Yield) -> WithHeartbeat<T> {
+        assert!(every > 0, "with_heartbeat needs a positive interval");
+        WithHeartbeat {
+            iter: self,
+            every,
+            beat,
+            since_beat: 0,
+        }
+    }
+}
+
+impl<T> Iterator for WithHeartbeat<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.since_beat == self.every {
+            self.since_beat = 0;
+            return Some(self.beat.clone());
+        }
+        let y = self.iter.next()?;
+        self.since_beat += 1;
+        Some(y)
+    }
+}
+
+impl<T> FusedIterator for WithHeartbeat<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn beat_positions() {
+        let g = gen_iter!({
+            for c in "abcdefg".chars() {
+                yield c;
+            }
+        }).with_heartbeat(3, '.');
+
+        assert!(g.eq("abc.def.g".chars()));
+    }
+
+    #[test]
+    fn beat_after_last_full_group() {
+        let mut g = gen_iter!({
+            for c in "abcd".chars() {
+                yield c;
+            }
+        }).with_heartbeat(2, '.');
+
+        assert!(g.by_ref().eq("ab.cd.".chars()));
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield 0;
+            }
+        }).with_heartbeat(1, 9);
+
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_interval() {
+        gen_iter!({
+            yield 1;
+        }).with_heartbeat(0, 0);
+    }
+}
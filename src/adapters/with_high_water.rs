@@ -0,0 +1,114 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator keeping the maximum of the yields so far,
+/// created by [`GenIter::with_high_water`].
+#[derive(Copy, Clone, Debug)]
+pub struct WithHighWater<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    high: Option<T::Yield>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{
+    /// yield the items unchanged, keeping their maximum, see [`WithHighWater::high_water`].
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 3;
+    ///     yield 8;
+    ///     yield 5;
+    /// }).with_high_water();
+    ///
+    /// assert_eq!(g.high_water(), None);
+    /// g.next();
+    /// assert_eq!(g.high_water(), Some(&3));
+    /// g.by_ref().for_each(drop);
+    /// assert_eq!(g.high_water(), Some(&8));
+    /// ```
+    #[inline]
+    pub fn with_high_water(self) -> WithHighWater<T> {
+        WithHighWater {
+            iter: self,
+            high: None,
+        }
+    }
+}
+
+impl<T> WithHighWater<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// the maximum of the items yielded so far, `None` before the first one
+    #[inline]
+    pub fn high_water(&self) -> Option<&T::Yield> {
+        self.high.as_ref()
+    }
+}
+
+impl<T> Iterator for WithHighWater<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        match self.high {
+            Some(ref h) if *h >= y => (),
+            _ => self.high = Some(y.clone()),
+        }
+        Some(y)
+    }
+}
+
+impl<T> FusedIterator for WithHighWater<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn partial_and_full() {
+        let mut g = gen_iter!({
+            for &i in &[2, 7, 1, 7, 9, 4] {
+                yield i;
+            }
+        }).with_high_water();
+
+        assert!(g.by_ref().take(3).eq([2, 7, 1].iter().cloned()));
+        assert_eq!(g.high_water(), Some(&7));
+
+        assert!(g.by_ref().eq([7, 9, 4].iter().cloned()));
+        assert_eq!(g.high_water(), Some(&9));
+    }
+
+    #[test]
+    fn empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield 0.0;
+            }
+        }).with_high_water();
+
+        assert_eq!(g.next(), None);
+        assert_eq!(g.high_water(), None);
+    }
+}
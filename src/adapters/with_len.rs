@@ -0,0 +1,167 @@
+use core::ops::Generator;
+use core::iter::{Iterator, ExactSizeIterator, FusedIterator};
+use core::marker::Unpin;
+use core::fmt;
+
+use GenIterReturn;
+
+/// a [`GenIterReturn`] with a declared number of yields,
+/// created by [`GenIterReturn::with_len`].
+///
+/// `&mut WithLen<G>` is an `ExactSizeIterator` reporting the remaining yields,
+/// and the return value can be got after the iterator is exhausted.
+pub struct WithLen<G: Generator + Unpin> {
+    inner: GenIterReturn<G>,
+    remaining: usize,
+}
+
+impl<G: Generator + Unpin> Clone for WithLen<G>
+where
+    GenIterReturn<G>: Clone,
+{
+    fn clone(&self) -> Self {
+        WithLen {
+            inner: self.inner.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<G: Generator + Unpin> fmt::Debug for WithLen<G>
+where
+    GenIterReturn<G>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithLen")
+            .field("inner", &self.inner)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<G: Generator + Unpin> GenIterReturn<G> {
+    /// declare that the generator yields exactly `len` more values
+    ///
+    /// it is a contract violation if the generator yields more or fewer values,
+    /// this is checked by debug assertions.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     return "done";
+    /// }).with_len(2);
+    ///
+    /// assert_eq!(g.len(), 2);
+    /// assert_eq!((&mut g).collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(g.return_or_self().ok(), Some("done"));
+    /// ```
+    #[inline]
+    pub fn with_len(self, len: usize) -> WithLen<G> {
+        WithLen {
+            inner: self,
+            remaining: len,
+        }
+    }
+}
+
+impl<G: Generator + Unpin> WithLen<G> {
+    /// the number of remaining yields
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.remaining
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.inner.is_done()
+    }
+
+    #[inline]
+    pub fn return_or_self(self) -> Result<G::Return, Self> {
+        let remaining = self.remaining;
+        self.inner.return_or_self().map_err(|inner| WithLen { inner, remaining })
+    }
+}
+
+impl<G: Generator + Unpin> Iterator for &mut WithLen<G> {
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (&mut self.inner).next() {
+            Some(y) => {
+                debug_assert!(self.remaining > 0, "generator yields more than the declared length");
+                self.remaining = self.remaining.saturating_sub(1);
+                Some(y)
+            },
+            None => {
+                debug_assert_eq!(self.remaining, 0, "generator yields less than the declared length");
+                None
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<G: Generator + Unpin> ExactSizeIterator for &mut WithLen<G> {}
+
+impl<G: Generator + Unpin> FusedIterator for &mut WithLen<G> {}
+
+#[cfg(test)]
+mod tests {
+    use core::iter::ExactSizeIterator;
+
+    fn exact_len<I: ExactSizeIterator>(iter: I) -> usize {
+        iter.len()
+    }
+
+    #[test]
+    fn len_and_return() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            yield 3;
+            return "done";
+        }).with_len(3);
+
+        assert_eq!(exact_len(&mut g), 3);
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!(exact_len(&mut g), 2);
+        assert_eq!(g.len(), 2);
+        assert_eq!((&mut g).next(), Some(2));
+        assert_eq!((&mut g).next(), Some(3));
+        assert_eq!(exact_len(&mut g), 0);
+        assert!(g.is_empty());
+        assert!(!g.is_done());
+
+        assert_eq!((&mut g).next(), None);
+        assert!(g.is_done());
+        assert_eq!(exact_len(&mut g), 0);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "less than the declared length")]
+    fn too_short() {
+        let mut g = gen_iter_return!({
+            yield 1;
+        }).with_len(2);
+
+        for _ in &mut g {}
+    }
+}
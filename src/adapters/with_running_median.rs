@@ -0,0 +1,117 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::cmp::Reverse;
+use alloc::collections::BinaryHeap;
+
+use GenIter;
+
+/// an iterator yielding each item with the median of the items so far,
+/// created by [`GenIter::with_running_median`].
+#[derive(Clone, Debug)]
+pub struct WithRunningMedian<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Ord,
+{
+    iter: GenIter<T>,
+    // the smaller half, `low.len()` is `high.len()` or `high.len() + 1`
+    low: BinaryHeap<T::Yield>,
+    high: BinaryHeap<Reverse<T::Yield>>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Ord + Clone + Into<f64>,
+{
+    /// yield `(item, median)` of the items so far, using a max-heap and a min-heap.
+    ///
+    /// for an even count, the median is the mean of the two middle items.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let medians: Vec<f64> = gen_iter!({
+    ///     yield 5;
+    ///     yield 1;
+    ///     yield 3;
+    /// }).with_running_median().map(|(_, m)| m).collect();
+    ///
+    /// assert_eq!(medians, [5.0, 3.0, 3.0]);
+    /// ```
+    #[inline]
+    pub fn with_running_median(self) -> WithRunningMedian<T> {
+        WithRunningMedian {
+            iter: self,
+            low: BinaryHeap::new(),
+            high: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<T> Iterator for WithRunningMedian<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Ord + Clone + Into<f64>,
+{
+    type Item = (T::Yield, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+
+        match self.low.peek() {
+            Some(top) if y > *top => self.high.push(Reverse(y.clone())),
+            _ => self.low.push(y.clone()),
+        }
+        if self.low.len() > self.high.len() + 1 {
+            let v = self.low.pop()?;
+            self.high.push(Reverse(v));
+        } else if self.high.len() > self.low.len() {
+            let Reverse(v) = self.high.pop()?;
+            self.low.push(v);
+        }
+
+        let lo: f64 = self.low.peek()?.clone().into();
+        let median = if self.low.len() > self.high.len() {
+            lo
+        } else {
+            let hi: f64 = self.high.peek()?.0.clone().into();
+            (lo + hi) / 2.0
+        };
+        Some((y, median))
+    }
+}
+
+impl<T> FusedIterator for WithRunningMedian<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Ord + Clone + Into<f64>,
+{}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn odd_and_even_counts() {
+        let medians = gen_iter!({
+            for &i in &[5u32, 15, 1, 3, 8, 7, 9, 10] {
+                yield i;
+            }
+        }).with_running_median().map(|(_, m)| m);
+
+        let expected = [5.0, 10.0, 5.0, 4.0, 5.0, 6.0, 7.0, 7.5];
+        assert!(medians.eq(expected.iter().cloned()));
+    }
+
+    #[test]
+    fn items_unchanged() {
+        let items = gen_iter!({
+            yield 2u8;
+            yield 1u8;
+        }).with_running_median().map(|(y, _)| y);
+
+        assert!(items.eq([2, 1].iter().cloned()));
+    }
+}
@@ -0,0 +1,119 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding each item with an increasing sequence id,
+/// created by [`GenIter::with_sequence_ids`].
+#[derive(Copy, Clone, Debug)]
+pub struct WithSequenceIds<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    next_id: u64,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// yield `(seq_id, item)` with ids counting up from `start`.
+    ///
+    /// a restarted generator can continue the ids from [`WithSequenceIds::next_id`].
+    /// the ids saturate at `u64::MAX`, all the items after it get `u64::MAX`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield "a";
+    ///     yield "b";
+    /// }).with_sequence_ids(100);
+    ///
+    /// assert_eq!(g.next(), Some((100, "a")));
+    /// assert_eq!(g.next_id(), 101);
+    ///
+    /// let mut restarted = gen_iter!({
+    ///     yield "c";
+    /// }).with_sequence_ids(g.next_id());
+    ///
+    /// assert_eq!(restarted.next(), Some((101, "c")));
+    /// ```
+    #[inline]
+    pub fn with_sequence_ids(self, start: u64) -> WithSequenceIds<T> {
+        WithSequenceIds {
+            iter: self,
+            next_id: start,
+        }
+    }
+}
+
+impl<T> WithSequenceIds<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// the id of the next item
+    #[inline]
+    pub fn next_id(&self) -> u64 {
+        self.next_id
+    }
+}
+
+impl<T> Iterator for WithSequenceIds<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = (u64, T::Yield);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        let id = self.next_id;
+        self.next_id = id.saturating_add(1);
+        Some((id, y))
+    }
+}
+
+impl<T> FusedIterator for WithSequenceIds<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Generator;
+    use GenIter;
+
+    fn letters(s: &'static str) -> GenIter<impl Generator<Yield = char, Return = ()> + Unpin> {
+        gen_iter!(move {
+            for c in s.chars() {
+                yield c;
+            }
+        })
+    }
+
+    #[test]
+    fn continue_after_restart() {
+        let mut g = letters("ab").with_sequence_ids(0);
+        assert_eq!(g.next_id(), 0);
+        assert!((&mut g).eq([(0, 'a'), (1, 'b')].iter().cloned()));
+        assert_eq!(g.next_id(), 2);
+
+        let g = letters("cd").with_sequence_ids(g.next_id());
+        assert!(g.eq([(2, 'c'), (3, 'd')].iter().cloned()));
+    }
+
+    #[test]
+    fn saturate() {
+        let mut g = letters("abc").with_sequence_ids(u64::MAX - 1);
+
+        assert_eq!(g.next(), Some((u64::MAX - 1, 'a')));
+        assert_eq!(g.next(), Some((u64::MAX, 'b')));
+        assert_eq!(g.next(), Some((u64::MAX, 'c')));
+        assert_eq!(g.next_id(), u64::MAX);
+    }
+}
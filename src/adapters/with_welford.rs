@@ -0,0 +1,101 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator yielding each item with the running mean and variance,
+/// created by [`GenIter::with_welford`].
+#[derive(Copy, Clone, Debug)]
+pub struct WithWelford<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<f64> + Clone,
+{
+    /// yield `(item, mean, variance)` of the items so far, using Welford's online algorithm.
+    ///
+    /// the variance is the population variance, it is `0.0` after the first item.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let (_, mean, variance) = gen_iter!({
+    ///     yield 2;
+    ///     yield 4;
+    /// }).with_welford().last().unwrap();
+    ///
+    /// assert_eq!((mean, variance), (3.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn with_welford(self) -> WithWelford<T> {
+        WithWelford {
+            iter: self,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+}
+
+impl<T> Iterator for WithWelford<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<f64> + Clone,
+{
+    type Item = (T::Yield, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.iter.next()?;
+        let x: f64 = y.clone().into();
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        Some((y, self.mean, self.m2 / self.count as f64))
+    }
+}
+
+impl<T> FusedIterator for WithWelford<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<f64> + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    fn close(a: f64, b: f64) -> bool {
+        let d = a - b;
+        d < 1e-9 && d > -1e-9
+    }
+
+    #[test]
+    fn mean_and_variance() {
+        let mut g = gen_iter!({
+            for &x in &[2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+                yield x;
+            }
+        }).with_welford();
+
+        assert_eq!(g.next(), Some((2.0, 2.0, 0.0)));
+        let (y, mean, variance) = g.next().unwrap();
+        assert_eq!(y, 4.0);
+        assert!(close(mean, 3.0));
+        assert!(close(variance, 1.0));
+
+        let (_, mean, variance) = g.last().unwrap();
+        assert!(close(mean, 5.0));
+        assert!(close(variance, 4.0));
+    }
+}
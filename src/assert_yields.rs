@@ -0,0 +1,72 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use core::fmt::Debug;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// drain the generator `g` and assert that it yields exactly `expected`,
+/// on mismatch it panics with the first differing index and both sequences.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::assert_yields;
+///
+/// assert_yields(|| {
+///     yield 1;
+///     yield 2;
+/// }, &[1, 2]);
+/// ```
+pub fn assert_yields<G>(g: G, expected: &[G::Yield])
+where
+    G: Generator<Return = ()> + Unpin,
+    G::Yield: PartialEq + Debug,
+{
+    let actual: Vec<_> = GenIter::new(g).collect();
+    if actual[..] == *expected {
+        return;
+    }
+    let index = actual.iter()
+        .zip(expected)
+        .position(|(a, e)| a != e)
+        .unwrap_or_else(|| actual.len().min(expected.len()));
+    panic!(
+        "generator yields differ at index {}: actual {:?}, expected {:?}\n  actual:   {:?}\n  expected: {:?}",
+        index, actual.get(index), expected.get(index), actual, expected,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assert_yields;
+
+    #[test]
+    fn matching() {
+        assert_yields(|| {
+            for i in 0..3 {
+                yield i;
+            }
+        }, &[0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "differ at index 1: actual Some(1), expected Some(5)")]
+    fn mismatch() {
+        assert_yields(|| {
+            for i in 0..3 {
+                yield i;
+            }
+        }, &[0, 5, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "differ at index 2: actual None, expected Some(2)")]
+    fn too_short() {
+        assert_yields(|| {
+            yield 0;
+            yield 1;
+        }, &[0, 1, 2]);
+    }
+}
@@ -0,0 +1,79 @@
+use core::ops::{Generator, ControlFlow};
+use core::marker::Unpin;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// resume the generator and call `step` on each yield,
+    /// until the generator is done or `step` returns `ControlFlow::Break`.
+    ///
+    /// after a break the generator stays suspended, and can be driven again later.
+    /// returns `ControlFlow::Break(())` if `step` stopped it,
+    /// `ControlFlow::Continue(())` if the generator is done.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::ops::ControlFlow;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     for i in 1..=5 {
+    ///         yield i;
+    ///     }
+    /// });
+    ///
+    /// let mut sum = 0;
+    /// g.drive_with(|&i| {
+    ///     sum += i;
+    ///     if sum >= 3 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// });
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(g.next(), Some(3));
+    /// ```
+    pub fn drive_with<F>(&mut self, mut step: F) -> ControlFlow<()>
+    where
+        F: FnMut(&T::Yield) -> ControlFlow<()>,
+    {
+        for y in self {
+            step(&y)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::ControlFlow;
+
+    #[test]
+    fn break_and_resume() {
+        let mut g = gen_iter!({
+            for i in 0..6 {
+                yield i;
+            }
+        });
+
+        let mut seen = [0; 6];
+        let mut count = 0;
+        let flow = g.drive_with(|&i| {
+            seen[count] = i;
+            count += 1;
+            if i == 2 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        });
+        assert_eq!(flow, ControlFlow::Break(()));
+        assert_eq!(count, 3);
+
+        let flow = g.drive_with(|&i| {
+            seen[count] = i;
+            count += 1;
+            ControlFlow::Continue(())
+        });
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert_eq!(seen, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(g.next(), None);
+    }
+}
@@ -0,0 +1,72 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FromIterator};
+use core::marker::Unpin;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Eq + Hash,
+{
+    /// drain the generator and count each distinct yield,
+    /// collecting the `(value, count)` pairs into `C`, in unspecified order.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use std::collections::HashMap;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let counts: HashMap<_, _> = gen_iter!({
+    ///     for c in "abaca".chars() {
+    ///         yield c;
+    ///     }
+    /// }).frequencies();
+    ///
+    /// assert_eq!(counts[&'a'], 3);
+    /// assert_eq!(counts[&'b'], 1);
+    /// ```
+    pub fn frequencies<C>(self) -> C
+    where
+        C: FromIterator<(T::Yield, usize)>,
+    {
+        let mut counts = HashMap::new();
+        for y in self {
+            *counts.entry(y).or_insert(0) += 1;
+        }
+        counts.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn frequencies() {
+        let counts: HashMap<_, _> = gen_iter!({
+            for &s in &["a", "b", "a", "c", "a"] {
+                yield s;
+            }
+        }).frequencies();
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 1);
+        assert_eq!(counts["c"], 1);
+    }
+
+    #[test]
+    fn into_btree_map() {
+        let counts: BTreeMap<_, _> = gen_iter!({
+            yield 2;
+            yield 1;
+            yield 2;
+        }).frequencies();
+
+        assert!(counts.into_iter().eq([(1, 1), (2, 2)].iter().cloned()));
+    }
+}
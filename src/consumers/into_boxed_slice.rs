@@ -0,0 +1,57 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// drain the generator into a boxed slice, to be iterated again later.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let items = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// }).into_boxed_slice();
+    ///
+    /// assert_eq!(&*items, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T::Yield]> {
+        self.collect::<Vec<_>>().into_boxed_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn contents_and_len() {
+        let items = gen_iter!({
+            for i in 0..5 {
+                yield i * i;
+            }
+        }).into_boxed_slice();
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(&*items, [0, 1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn empty() {
+        let items = gen_iter!({
+            if false {
+                yield 0;
+            }
+        }).into_boxed_slice();
+
+        assert!(items.is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: PartialOrd + Clone,
+{
+    /// drain the generator and return its minimum and maximum yields in one pass,
+    /// `None` if nothing is yielded.
+    ///
+    /// like `Iterator::min` and `Iterator::max`, the first minimum
+    /// and the last maximum are returned among equal items.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     for &i in &[3, 1, 4, 1, 5] {
+    ///         yield i;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(g.min_max(), Some((1, 5)));
+    /// ```
+    pub fn min_max(mut self) -> Option<(T::Yield, T::Yield)> {
+        let first = self.next()?;
+        let (mut min, mut max) = (first.clone(), first);
+        for y in self {
+            if y < min {
+                min = y;
+            } else if y >= max {
+                max = y;
+            }
+        }
+        Some((min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn min_max() {
+        let g = gen_iter!({
+            for &i in &[3, 1, 4, 1, 5] {
+                yield i;
+            }
+        });
+
+        assert_eq!(g.min_max(), Some((1, 5)));
+    }
+
+    #[test]
+    fn single_and_empty() {
+        assert_eq!(gen_iter!({ yield 7; }).min_max(), Some((7, 7)));
+
+        let empty = gen_iter!({
+            if false {
+                yield 0;
+            }
+        });
+        assert_eq!(empty.min_max(), None);
+    }
+}
@@ -0,0 +1,32 @@
+//! methods of [`GenIter`](crate::GenIter) and [`GenIterReturn`](crate::GenIterReturn) that drive or consume the generator
+
+mod drive_with;
+mod min_max;
+mod sample_at;
+
+#[cfg(feature = "alloc")]
+mod partition_tagged;
+
+#[cfg(feature = "alloc")]
+mod into_boxed_slice;
+
+#[cfg(feature = "alloc")]
+mod percentile_bins;
+
+#[cfg(feature = "alloc")]
+mod run_incremental;
+#[cfg(feature = "alloc")]
+pub use self::run_incremental::*;
+
+#[cfg(feature = "std")]
+mod frequencies;
+
+#[cfg(feature = "std")]
+mod spawn_with_return;
+#[cfg(feature = "std")]
+pub use self::spawn_with_return::*;
+
+#[cfg(feature = "std")]
+mod spawn_pool;
+#[cfg(feature = "std")]
+pub use self::spawn_pool::*;
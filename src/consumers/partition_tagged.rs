@@ -0,0 +1,65 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use {GenIter, TaggedItem};
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// drain the generator, separating the data and the control items
+    /// as classified by `classify`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::{gen_iter, TaggedItem};
+    ///
+    /// let (data, control) = gen_iter!({
+    ///     yield TaggedItem::Data(1);
+    ///     yield TaggedItem::Control("flush");
+    ///     yield TaggedItem::Data(2);
+    /// }).partition_tagged(|item| item);
+    ///
+    /// assert_eq!(data, [1, 2]);
+    /// assert_eq!(control, ["flush"]);
+    /// ```
+    pub fn partition_tagged<D, C, F>(self, mut classify: F) -> (Vec<D>, Vec<C>)
+    where
+        F: FnMut(T::Yield) -> TaggedItem<D, C>,
+    {
+        let mut data = Vec::new();
+        let mut control = Vec::new();
+        for y in self {
+            match classify(y) {
+                TaggedItem::Data(d) => data.push(d),
+                TaggedItem::Control(c) => control.push(c),
+            }
+        }
+        (data, control)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use TaggedItem;
+
+    #[test]
+    fn mixed_stream() {
+        let (data, control) = gen_iter!({
+            for i in 0..6 {
+                yield i;
+            }
+        }).partition_tagged(|i| {
+            if i % 3 == 0 {
+                TaggedItem::Control(i)
+            } else {
+                TaggedItem::Data(i * 10)
+            }
+        });
+
+        assert_eq!(data, [10, 20, 40, 50]);
+        assert_eq!(control, [0, 3]);
+    }
+}
@@ -0,0 +1,94 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+    T::Yield: Into<f64>,
+{
+    /// drain the generator and count the yields in the bins bounded by `edges`,
+    /// returning `edges.len() + 1` counts.
+    ///
+    /// the first bin counts the yields below `edges[0]`, the bin `i` the yields in
+    /// `edges[i - 1]..edges[i]`, and the last one the yields from the last edge up.
+    /// NaN yields are counted in the first bin.
+    ///
+    /// panics if `edges` is not sorted in ascending order.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     for &i in &[1u8, 5, 10, 15, 20, 25] {
+    ///         yield i;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(g.percentile_bins(&[10.0, 20.0]), [2, 2, 2]);
+    /// ```
+    pub fn percentile_bins(self, edges: &[f64]) -> Vec<u64> {
+        assert!(
+            edges.windows(2).all(|w| w[0] <= w[1]),
+            "percentile_bins edges must be sorted"
+        );
+        let mut counts = vec![0u64; edges.len() + 1];
+        for y in self {
+            let x = y.into();
+            counts[edges.partition_point(|&e| e <= x)] += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn percentile_bins() {
+        let g = gen_iter!({
+            for i in 0..100u32 {
+                yield i;
+            }
+        });
+
+        assert_eq!(g.percentile_bins(&[25.0, 50.0, 75.0]), [25, 25, 25, 25]);
+    }
+
+    #[test]
+    fn underflow_and_overflow() {
+        let g = gen_iter!({
+            yield -1.0;
+            yield 0.0;
+            yield 0.5;
+            yield 1.0;
+            yield 7.0;
+        });
+
+        assert_eq!(g.percentile_bins(&[0.0, 1.0]), [1, 2, 2]);
+    }
+
+    #[test]
+    fn no_edges() {
+        let g = gen_iter!({
+            yield 1.0;
+            yield 2.0;
+        });
+
+        assert_eq!(g.percentile_bins(&[]), [2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsorted_edges() {
+        let g = gen_iter!({
+            yield 1.0;
+        });
+
+        g.percentile_bins(&[2.0, 1.0]);
+    }
+}
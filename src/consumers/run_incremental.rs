@@ -0,0 +1,111 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// the result of one [`GenIter::run_incremental`] call
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Incremental<Y> {
+    items: Vec<Y>,
+    done: bool,
+}
+
+impl<Y> Incremental<Y> {
+    /// whether the generator completed during this run
+    #[inline]
+    pub fn done(&self) -> bool {
+        self.done
+    }
+
+    #[inline]
+    pub fn items(&self) -> &[Y] {
+        &self.items
+    }
+
+    #[inline]
+    pub fn into_items(self) -> Vec<Y> {
+        self.items
+    }
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// resume the generator at most `budget` times, collecting the yields.
+    ///
+    /// if the generator is not done, it stays suspended for the next run.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     yield 3;
+    /// });
+    ///
+    /// let tick = g.run_incremental(2);
+    /// assert!(!tick.done());
+    /// assert_eq!(tick.into_items(), [1, 2]);
+    ///
+    /// let tick = g.run_incremental(2);
+    /// assert!(tick.done());
+    /// assert_eq!(tick.into_items(), [3]);
+    /// ```
+    pub fn run_incremental(&mut self, budget: usize) -> Incremental<T::Yield> {
+        // the budget may be far more than the generator yields
+        let mut items = Vec::new();
+        for _ in 0..budget {
+            match self.next() {
+                Some(y) => items.push(y),
+                None => return Incremental { items, done: true },
+            }
+        }
+        Incremental { items, done: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn three_ticks() {
+        let mut g = gen_iter!({
+            for i in 0..5 {
+                yield i;
+            }
+        });
+
+        let tick = g.run_incremental(2);
+        assert!(!tick.done());
+        assert_eq!(tick.items(), [0, 1]);
+
+        let tick = g.run_incremental(2);
+        assert!(!tick.done());
+        assert_eq!(tick.into_items(), [2, 3]);
+
+        let tick = g.run_incremental(2);
+        assert!(tick.done());
+        assert_eq!(tick.into_items(), [4]);
+
+        let tick = g.run_incremental(2);
+        assert!(tick.done());
+        assert!(tick.items().is_empty());
+    }
+
+    #[test]
+    fn huge_budget() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        });
+
+        let tick = g.run_incremental(usize::MAX);
+        assert!(tick.done());
+        assert_eq!(tick.into_items(), [1, 2]);
+    }
+}
@@ -0,0 +1,57 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+
+use GenIter;
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// resume the generator `index + 1` times and return the yield at `index`,
+    /// `None` if the generator is done before.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     for i in 0..10 {
+    ///         yield i * i;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(g.sample_at(3), Some(9));
+    /// ```
+    #[inline]
+    pub fn sample_at(mut self, index: usize) -> Option<T::Yield> {
+        self.nth(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Generator;
+    use GenIter;
+
+    fn tens() -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+        gen_iter!({
+            for i in 0..5 {
+                yield i * 10;
+            }
+        })
+    }
+
+    #[test]
+    fn valid_index() {
+        assert_eq!(tens().sample_at(0), Some(0));
+        assert_eq!(tens().sample_at(4), Some(40));
+    }
+
+    #[test]
+    fn past_the_end() {
+        assert_eq!(tens().sample_at(5), None);
+        assert_eq!(tens().sample_at(100), None);
+    }
+}
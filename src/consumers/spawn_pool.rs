@@ -0,0 +1,198 @@
+use core::ops::Generator;
+use core::iter::{Iterator, IntoIterator};
+use core::marker::Unpin;
+use core::any::Any;
+use std::boxed::Box;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::panic::{self, AssertUnwindSafe};
+use std::vec::Vec;
+
+use GenIter;
+
+/// a handle to the worker threads of [`spawn_pool`]
+#[derive(Debug)]
+pub struct PoolHandle {
+    handles: Vec<JoinHandle<Option<Box<dyn Any + Send>>>>,
+}
+
+impl PoolHandle {
+    /// block until all the workers stop,
+    /// the first panic of a generator is propagated to the caller.
+    pub fn join(self) {
+        let mut first = None;
+        for handle in self.handles {
+            let panicked = match handle.join() {
+                Ok(p) => p,
+                Err(e) => Some(e),
+            };
+            if first.is_none() {
+                first = panicked;
+            }
+        }
+        if let Some(e) = first {
+            panic::resume_unwind(e);
+        }
+    }
+}
+
+/// drive the generators on a pool of `workers` threads,
+/// sending the yields of all of them through one channel.
+///
+/// each worker takes the next pending generator and drives it to completion.
+/// the yields of one generator arrive in order, but the order across generators is unspecified.
+/// the channel is closed once all the generators are done,
+/// and the workers stop early if the `Receiver` is dropped.
+///
+/// if a generator panics, its remaining yields are lost and its worker goes on with the next generator,
+/// the panic is propagated by [`PoolHandle::join`].
+///
+/// panics if `workers` is `0`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::{gen_iter, spawn_pool};
+///
+/// let gens = (0..4).map(|i| gen_iter!(move {
+///     yield i * 10;
+///     yield i * 10 + 1;
+/// }));
+///
+/// let (rx, pool) = spawn_pool(gens, 2);
+/// let mut items: Vec<_> = rx.iter().collect();
+/// pool.join();
+///
+/// items.sort();
+/// assert_eq!(items, [0, 1, 10, 11, 20, 21, 30, 31]);
+/// ```
+pub fn spawn_pool<T, I>(gens: I, workers: usize) -> (Receiver<T::Yield>, PoolHandle)
+where
+    T: Generator<Return = ()> + Unpin + Send + 'static,
+    T::Yield: Send + 'static,
+    I: IntoIterator<Item = GenIter<T>>,
+{
+    assert!(workers > 0, "spawn_pool needs at least one worker");
+    let queue: VecDeque<GenIter<T>> = gens.into_iter().collect();
+    let workers = workers.min(queue.len());
+    let queue = Arc::new(Mutex::new(queue));
+    let (tx, rx) = mpsc::channel();
+    let handles = (0..workers).map(|_| {
+        let queue = Arc::clone(&queue);
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let mut panicked = None;
+            loop {
+                let next = queue.lock().unwrap().pop_front();
+                let g = match next {
+                    Some(g) => g,
+                    None => return panicked,
+                };
+                // `Ok(false)` if the `Receiver` is dropped
+                let sent = panic::catch_unwind(AssertUnwindSafe(|| {
+                    for y in g {
+                        if tx.send(y).is_err() {
+                            return false;
+                        }
+                    }
+                    true
+                }));
+                match sent {
+                    Ok(true) => {},
+                    Ok(false) => return panicked,
+                    Err(e) => {
+                        if panicked.is_none() {
+                            panicked = Some(e);
+                        }
+                    },
+                }
+            }
+        })
+    }).collect();
+    (rx, PoolHandle { handles })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Generator;
+    use core::marker::Unpin;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::vec::Vec;
+    use GenIter;
+    use super::spawn_pool;
+
+    fn range(start: u32, end: u32) -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+        gen_iter!(move {
+            for i in start..end {
+                yield i;
+            }
+        })
+    }
+
+    #[test]
+    fn all_items_arrive() {
+        let gens = (0..10).map(|i| range(i * 100, i * 100 + 50));
+
+        let (rx, pool) = spawn_pool(gens, 3);
+        let mut items: Vec<_> = rx.iter().collect();
+        pool.join();
+        items.sort();
+
+        let mut expected: Vec<_> = (0..10).flat_map(|i| i * 100..i * 100 + 50).collect();
+        expected.sort();
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn order_within_generator() {
+        let (rx, pool) = spawn_pool(vec![range(0, 100)], 4);
+
+        assert!(rx.iter().eq(0..100));
+        pool.join();
+    }
+
+    #[test]
+    fn no_generators() {
+        let (rx, pool) = spawn_pool((0..0).map(|i| range(i, i)), 2);
+
+        assert_eq!(rx.recv().ok(), None);
+        pool.join();
+    }
+
+    #[test]
+    fn receiver_dropped() {
+        let (rx, pool) = spawn_pool(vec![range(0, 1000), range(0, 1000)], 1);
+
+        drop(rx);
+        pool.join();
+    }
+
+    #[test]
+    fn propagate_panic() {
+        fn up_to(n: u32, fail_at: u32) -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+            gen_iter!(move {
+                for i in 0..n {
+                    if i == fail_at {
+                        panic!("boom");
+                    }
+                    yield i;
+                }
+            })
+        }
+
+        let (rx, pool) = spawn_pool(vec![up_to(3, 2), up_to(3, 3)], 1);
+        let items: Vec<_> = rx.iter().collect();
+        assert_eq!(items, [0, 1, 0, 1, 2]);
+
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| pool.join())).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_workers() {
+        spawn_pool(vec![range(0, 1)], 0);
+    }
+}
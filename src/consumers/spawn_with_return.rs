@@ -0,0 +1,110 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::panic;
+
+use GenIterReturn;
+
+/// a handle to the return value of a generator running on another thread,
+/// created by [`GenIterReturn::spawn_with_return`].
+#[derive(Debug)]
+pub struct ReturnHandle<R> {
+    handle: JoinHandle<R>,
+}
+
+impl<R> ReturnHandle<R> {
+    /// block until the generator is done and get its return value,
+    /// a panic of the generator is propagated to the caller.
+    pub fn join(self) -> R {
+        match self.handle.join() {
+            Ok(r) => r,
+            Err(e) => panic::resume_unwind(e),
+        }
+    }
+}
+
+impl<G> GenIterReturn<G>
+where
+    G: Generator + Unpin + Send + 'static,
+    G::Yield: Send + 'static,
+    G::Return: Send + 'static,
+{
+    /// run the generator on a new thread, sending the yields through a channel.
+    ///
+    /// the generator is always driven to completion,
+    /// the yields are discarded if the `Receiver` is dropped.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let (rx, handle) = gen_iter_return!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     return "done";
+    /// }).spawn_with_return();
+    ///
+    /// assert_eq!(rx.iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(handle.join(), "done");
+    /// ```
+    pub fn spawn_with_return(self) -> (Receiver<G::Yield>, ReturnHandle<G::Return>) {
+        let (tx, rx) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let mut g = self;
+            for y in &mut g {
+                let _ = tx.send(y);
+            }
+            match g.return_or_self() {
+                Ok(r) => r,
+                Err(_) => unreachable!("the generator is done after the iteration"),
+            }
+        });
+        (rx, ReturnHandle { handle })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    #[test]
+    fn items_then_return() {
+        let (rx, handle) = gen_iter_return!({
+            for i in 0..100 {
+                yield i;
+            }
+            return "done";
+        }).spawn_with_return();
+
+        assert_eq!(rx.iter().collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
+        assert_eq!(handle.join(), "done");
+    }
+
+    #[test]
+    fn receiver_dropped() {
+        let (rx, handle) = gen_iter_return!({
+            for i in 0..100 {
+                yield i;
+            }
+            return 100;
+        }).spawn_with_return();
+
+        drop(rx);
+        assert_eq!(handle.join(), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn propagate_panic() {
+        let (_rx, handle) = gen_iter_return!({
+            yield 1;
+            if true {
+                panic!("boom");
+            }
+        }).spawn_with_return();
+
+        handle.join();
+    }
+}
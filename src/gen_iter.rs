@@ -1,14 +1,130 @@
-use core::ops::{Generator, GeneratorState};
-use core::iter::Iterator;
+use core::ops::{Generator, GeneratorState, Try};
+use core::iter::{Iterator, FusedIterator};
 use core::marker::Unpin;
 use core::pin::Pin;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// an iterator that holds an internal generator representing
 /// the iteration state
+///
+/// it is safe to call `next()` after the generator is done,
+/// it returns `None` without resuming the generator.
 #[derive(Copy, Clone, Debug)]
-pub struct GenIter<T>(pub T)
+pub struct GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    gen: T,
+    pub(crate) done: bool,
+    resumed: bool,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    pub fn new(gen: T) -> Self {
+        GenIter {
+            gen,
+            done: false,
+            resumed: false,
+        }
+    }
+
+    /// create the generator by calling `f`,
+    /// to wrap a function building generators from runtime parameters.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnOnce() -> T,
+    {
+        GenIter::new(f())
+    }
+
+    /// the generator, taking the place of the former public tuple field `.0`.
+    ///
+    /// the generator is returned as is, so it may be completed and must not be resumed again.
+    ///
+    /// ```
+    /// #![feature(generators, generator_trait)]
+    ///
+    /// use std::ops::{Generator, GeneratorState};
+    /// use std::pin::Pin;
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// });
+    /// assert_eq!(g.next(), Some(1));
+    ///
+    /// let mut gen = g.into_inner();
+    /// assert_eq!(Pin::new(&mut gen).resume(()), GeneratorState::Yielded(2));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.gen
+    }
+
+    /// whether the most recent `next()` resumed the generator,
+    /// `false` if it returned `None` because the generator was already done.
+    ///
+    /// the first `None` is returned by a real resume observing the completion,
+    /// so it is `true` for that call and `false` for all the later ones.
+    #[inline]
+    pub fn last_resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// a clone of `self` if the generator was never resumed, `None` otherwise,
+    /// not to duplicate a generator in the middle of its iteration.
+    ///
+    /// generators are `Clone` with the `generator_clone` feature,
+    /// a generator pinned on the stack as `Pin<&mut G>` is never `Clone`.
+    ///
+    /// ```
+    /// #![feature(generators, generator_clone)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let mut g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    /// });
+    ///
+    /// let c = g.try_clone().unwrap();
+    /// assert_eq!(g.next(), Some(1));
+    /// assert!(g.try_clone().is_none());
+    /// assert_eq!(c.collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn try_clone(&self) -> Option<Self>
+    where
+        T: Clone,
+    {
+        // `resumed` is only reset by `next()` after the generator is done
+        if self.done || self.resumed {
+            None
+        } else {
+            Some(self.clone())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G> GenIter<Pin<Box<G>>>
 where
-    T: Generator<Return = ()> + Unpin;
+    G: Generator<Return = ()>,
+{
+    /// pin the generator on the heap, so it does not need to be `Unpin`,
+    /// like a `static` generator holding borrows of its own locals across yields.
+    #[inline]
+    pub fn boxed(gen: G) -> Self {
+        GenIter::new(Box::pin(gen))
+    }
+}
 
 impl<T> Iterator for GenIter<T>
 where
@@ -18,20 +134,133 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match Pin::new(&mut self.0).resume(()) {
+        if self.done {
+            self.resumed = false;
+            return None;
+        }
+        self.resumed = true;
+        match Pin::new(&mut self.gen).resume(()) {
             GeneratorState::Yielded(n) => Some(n),
-            GeneratorState::Complete(()) => None,
+            GeneratorState::Complete(()) => {
+                self.done = true;
+                None
+            },
+        }
+    }
+
+    /// resume the generator in one loop, counting the yields
+    #[inline]
+    fn count(mut self) -> usize {
+        if self.done {
+            return 0;
+        }
+        let mut n = 0;
+        let mut gen = Pin::new(&mut self.gen);
+        while let GeneratorState::Yielded(_) = gen.as_mut().resume(()) {
+            n += 1;
+        }
+        n
+    }
+
+    /// resume the generator in one loop, keeping only the latest yield
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut last = None;
+        let mut gen = Pin::new(&mut self.gen);
+        while let GeneratorState::Yielded(y) = gen.as_mut().resume(()) {
+            last = Some(y);
+        }
+        last
+    }
+
+    /// resume the generator in one loop, dropping the first `n` yields
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.done {
+            self.resumed = false;
+            return None;
+        }
+        self.resumed = true;
+        let mut skip = n;
+        let mut gen = Pin::new(&mut self.gen);
+        loop {
+            match gen.as_mut().resume(()) {
+                GeneratorState::Yielded(y) => {
+                    if skip == 0 {
+                        return Some(y);
+                    }
+                    skip -= 1;
+                },
+                GeneratorState::Complete(()) => {
+                    self.done = true;
+                    return None;
+                },
+            }
+        }
+    }
+
+    /// resume the generator in one loop until `f` short-circuits,
+    /// the generator stays suspended after the item `f` stopped on.
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        if self.done {
+            self.resumed = false;
+            return R::from_output(init);
+        }
+        self.resumed = true;
+        let mut acc = init;
+        let mut gen = Pin::new(&mut self.gen);
+        loop {
+            match gen.as_mut().resume(()) {
+                GeneratorState::Yielded(y) => acc = f(acc, y)?,
+                GeneratorState::Complete(()) => {
+                    self.done = true;
+                    return R::from_output(acc);
+                },
+            }
+        }
+    }
+
+    /// resume the generator in one loop, without the per item state checks of `next()`.
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        if self.done {
+            return init;
+        }
+        let mut acc = init;
+        let mut gen = Pin::new(&mut self.gen);
+        loop {
+            match gen.as_mut().resume(()) {
+                GeneratorState::Yielded(y) => acc = f(acc, y),
+                GeneratorState::Complete(()) => return acc,
+            }
         }
     }
 }
 
+/// `GenIter<T>` satisfies the trait `FusedIterator`
+impl<T> FusedIterator for GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
 impl<G> From<G> for GenIter<G>
 where
     G: Generator<Return = ()> + Unpin,
 {
     #[inline]
     fn from(gen: G) -> Self {
-        GenIter(gen)
+        GenIter::new(gen)
     }
 }
 
@@ -53,19 +282,103 @@ where
 /// assert_eq!(g.next(), None);
 ///
 /// ```
+///
+/// with `hint = (lower, upper)` it creates a [`GenIterHinted`](crate::GenIterHinted) reporting that size hint,
+/// and with `len = n` a [`GenIterExact`](crate::GenIterExact) yielding exactly `n` items.
+///
+/// with `yields T` the yield type is fixed to `T` when it cannot be inferred,
+/// a type cannot be followed by `move`, so the movable form is `yields T, move { .. }`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::gen_iter;
+///
+/// let v: Vec<_> = gen_iter!(yields String {
+///     yield Default::default();
+///     yield "a".into();
+/// }).collect();
+///
+/// assert_eq!(v, ["", "a"]);
+/// ```
 #[macro_export]
 macro_rules! gen_iter {
     ($block: block) => {
-        $crate::GenIter(|| $block)
+        $crate::GenIter::new(|| $block)
     };
     (move $block: block) => {
-        $crate::GenIter(move || $block)
+        $crate::GenIter::new(move || $block)
+    };
+    (yields $ty: ty $block: block) => {
+        $crate::GenIter::new($crate::__yields::<$ty, _>(|| $block))
+    };
+    (yields $ty: ty, move $block: block) => {
+        $crate::GenIter::new($crate::__yields::<$ty, _>(move || $block))
+    };
+    (hint = ($lower: expr, $upper: expr) $block: block) => {
+        $crate::GenIterHinted::new(|| $block, $lower, $upper)
+    };
+    (hint = ($lower: expr, $upper: expr) move $block: block) => {
+        $crate::GenIterHinted::new(move || $block, $lower, $upper)
+    };
+    (len = $len: tt $block: block) => {
+        $crate::GenIterExact::new(|| $block, $len)
+    };
+    (len = $len: tt move $block: block) => {
+        $crate::GenIterExact::new(move || $block, $len)
+    }
+}
+
+/// macro to create a [`GenIter`] over a `static` generator pinned on the heap, by [`GenIter::boxed`]
+///
+/// the generator may hold borrows of its own locals across yields,
+/// and the `GenIter<Pin<Box<_>>>` can be returned or stored like any other value.
+///
+/// ```
+/// #![feature(generators, generator_trait)]
+///
+/// use std::ops::Generator;
+/// use gen_iter::{gen_iter_boxed, GenIter};
+///
+/// fn evens() -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+///     gen_iter_boxed!({
+///         let v = [2, 4, 6];
+///         for x in &v {
+///             yield *x; // `&v` is held across the yield
+///         }
+///     })
+/// }
+///
+/// assert_eq!(evens().collect::<Vec<_>>(), [2, 4, 6]);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! gen_iter_boxed {
+    ($block: block) => {
+        $crate::GenIter::boxed(static move || $block)
+    };
+    (yields $ty: ty $block: block) => {
+        $crate::GenIter::boxed($crate::__yields::<$ty, _>(static move || $block))
     }
 }
 
+/// fix the yield type of a generator, for `gen_iter!(yields T { .. })`
+#[doc(hidden)]
+#[inline]
+pub fn __yields<Y, G>(gen: G) -> G
+where
+    G: Generator<Yield = Y, Return = ()>,
+{
+    gen
+}
+
 
 #[cfg(test)]
 mod tests {
+    use core::cell::Cell;
+    use core::iter::FusedIterator;
+    use core::marker::Unpin;
+    use core::ops::Generator;
     use super::GenIter;
 
     #[test]
@@ -103,4 +416,348 @@ mod tests {
         assert_eq!(g.next(), Some(2));
         assert_eq!(g.next(), None);
     }
+
+    #[test]
+    fn next_after_done() {
+        let resumes = Cell::new(0);
+        let mut g = gen_iter!({
+            resumes.set(resumes.get() + 1);
+            yield 1;
+            resumes.set(resumes.get() + 1);
+        });
+
+        assert_eq!(g.next(), Some(1));
+        for _ in 0..5 {
+            assert_eq!(g.next(), None);
+        }
+        assert_eq!(resumes.get(), 2);
+    }
+
+    #[test]
+    fn next_after_done_empty() {
+        let mut g = gen_iter!({
+            if false {
+                yield 1;
+            }
+        });
+
+        for _ in 0..5 {
+            assert_eq!(g.next(), None);
+        }
+    }
+
+    #[test]
+    fn from_fn() {
+        fn countdown(n: u32) -> impl Generator<Yield = u32, Return = ()> + Unpin {
+            move || {
+                for i in (0..n).rev() {
+                    yield i;
+                }
+            }
+        }
+
+        let g = GenIter::from_fn(|| countdown(3));
+        assert!(g.eq([2, 1, 0].iter().cloned()));
+    }
+
+    #[test]
+    fn fold() {
+        fn fib() -> GenIter<impl Generator<Yield = u64, Return = ()> + Unpin> {
+            gen_iter!({
+                let (mut a, mut b) = (0u64, 1u64);
+                for _ in 0..50 {
+                    yield a;
+                    let c = a + b;
+                    a = b;
+                    b = c;
+                }
+            })
+        }
+
+        // order sensitive, to check that every yield is folded in order
+        let hash = |acc: u64, x: u64| acc.wrapping_mul(31).wrapping_add(x);
+
+        let mut expected = 0;
+        for x in fib() {
+            expected = hash(expected, x);
+        }
+        assert_eq!(fib().fold(0, hash), expected);
+
+        let mut expected_partial = 0;
+        for x in fib().skip(1) {
+            expected_partial = hash(expected_partial, x);
+        }
+        let mut partial = fib();
+        assert_eq!(partial.next(), Some(0));
+        assert_eq!(partial.fold(0, hash), expected_partial);
+
+        let mut done = fib();
+        done.by_ref().for_each(drop);
+        assert_eq!(done.fold(7, hash), 7);
+    }
+
+    #[test]
+    fn try_fold_short_circuits() {
+        let mut g = gen_iter!({
+            for i in 0..10 {
+                yield i;
+            }
+        });
+
+        let r = g.try_fold(0, |acc, x| if x < 5 { Ok(acc + x) } else { Err(acc) });
+        assert_eq!(r, Err(10));
+        assert_eq!(g.next(), Some(6));
+    }
+
+    #[test]
+    fn try_fold_same_as_next() {
+        fn ten() -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+            gen_iter!({
+                for i in 0..10 {
+                    yield i;
+                }
+            })
+        }
+
+        fn by_next<I: Iterator<Item = u32>>(it: &mut I, stop: u32) -> Option<u32> {
+            let mut acc = 0;
+            for x in it {
+                if x == stop {
+                    return None;
+                }
+                acc += x;
+            }
+            Some(acc)
+        }
+
+        for &stop in &[0, 4, 9, 10] {
+            let mut g = ten();
+            let mut expected = ten();
+            let r = g.try_fold(0, |acc, x| if x == stop { None } else { Some(acc + x) });
+            assert_eq!(r, by_next(&mut expected, stop));
+            assert_eq!(g.done, expected.done);
+            assert_eq!(g.next(), expected.next());
+        }
+
+        let mut g = ten();
+        assert_eq!(g.try_fold(0, |acc, x| Some(acc + x)), Some(45));
+        assert!(g.done && g.last_resumed());
+        assert_eq!(g.try_fold(1, |acc, x| Some(acc + x)), Some(1));
+        assert!(!g.last_resumed());
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn count_and_last() {
+        fn three() -> GenIter<impl Generator<Yield = char, Return = ()> + Unpin> {
+            gen_iter!({
+                yield 'a';
+                yield 'b';
+                yield 'c';
+            })
+        }
+
+        assert_eq!(three().count(), 3);
+        assert_eq!(three().last(), Some('c'));
+
+        let mut g = three();
+        g.next();
+        assert_eq!(g.count(), 2);
+
+        let mut g = three();
+        g.by_ref().for_each(drop);
+        assert_eq!(g.last(), None);
+    }
+
+    #[test]
+    fn yields() {
+        let mut g = gen_iter!(yields u64 {
+            if let Some(x) = None {
+                yield x;
+            }
+        });
+        assert_eq!(g.next(), None);
+
+        let n = 3;
+        let v: [u8; 3] = [
+            gen_iter!(yields u8, move {
+                yield Default::default();
+                yield n;
+            }).sum(),
+            gen_iter!(yields u8, move {
+                for _ in 0..n {
+                    yield Default::default();
+                }
+            }).count() as u8,
+            gen_iter!(yields u8 {
+                yield From::from(true);
+            }).sum(),
+        ];
+        assert_eq!(v, [3, 3, 1]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn gen_iter_boxed_yields() {
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let g = gen_iter_boxed!(yields String {
+            let words = ["a", "b"];
+            for w in &words {
+                yield From::from(*w);
+            }
+        });
+        assert_eq!(g.collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn nth() {
+        fn five() -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+            gen_iter!({
+                for i in 0..5 {
+                    yield i;
+                }
+            })
+        }
+
+        let mut g = five();
+        assert_eq!(g.nth(1), Some(1));
+        assert_eq!(g.nth(1), Some(3));
+        assert_eq!(g.next(), Some(4));
+        assert_eq!(g.nth(1), None);
+
+        let mut g = five();
+        assert_eq!(g.nth(5), None);
+        assert!(g.done);
+        assert_eq!(g.next(), None);
+
+        let mut g = five();
+        assert_eq!(g.nth(100), None);
+        assert_eq!(g.nth(2), None);
+        assert!(!g.last_resumed());
+    }
+
+    #[test]
+    fn fused() {
+        fn assert_fused<I: FusedIterator>(_: &I) {}
+
+        let mut g = gen_iter!({
+            yield 1;
+        });
+        assert_fused(&g);
+
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+
+        let mut f = gen_iter!({
+            yield 1;
+        }).fuse();
+        assert_eq!(f.next(), Some(1));
+        assert_eq!(f.next(), None);
+        assert_eq!(f.next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn gen_iter_boxed() {
+        use core::ops::Generator;
+        use core::pin::Pin;
+        use alloc::boxed::Box;
+        use GenIterReturn;
+
+        fn chars(s: &'static str) -> GenIter<Pin<Box<impl Generator<Yield = char, Return = ()>>>> {
+            gen_iter_boxed!({
+                let mut it = s.chars();
+                let it = &mut it;
+                for c in it {
+                    yield c;
+                }
+            })
+        }
+
+        assert!(chars("abc").eq(['a', 'b', 'c'].iter().cloned()));
+
+        let mut g = GenIterReturn::new(Box::pin(static || {
+            let v = [1, 2];
+            for x in &v {
+                yield *x;
+            }
+            v.len()
+        }));
+        assert!((&mut g).eq([1, 2].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        let g = GenIter::boxed(static || {
+            let words = ["a", "bb", "ccc"];
+            let longest = &words[2];
+            for w in &words {
+                yield w.len() + longest.len();
+            }
+        });
+
+        assert!(g.eq([4, 5, 6].iter().cloned()));
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut g = gen_iter!({
+            yield 1;
+            yield 2;
+        });
+        assert_eq!(g.next(), Some(1));
+
+        let mut g = GenIter::new(g.into_inner());
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), None);
+    }
+
+    #[test]
+    fn try_clone() {
+        fn two() -> GenIter<impl Generator<Yield = u8, Return = ()> + Unpin + Clone> {
+            gen_iter!({
+                yield 1;
+                yield 2;
+            })
+        }
+
+        let mut g = two();
+        let mut c = g.try_clone().unwrap();
+        assert_eq!(g.next(), Some(1));
+        assert!(g.try_clone().is_none());
+        assert_eq!(g.next(), Some(2));
+        assert!(g.try_clone().is_none());
+        assert_eq!(g.next(), None);
+        assert!(g.try_clone().is_none());
+        assert_eq!(g.next(), None);
+        assert!(g.try_clone().is_none());
+
+        assert!(c.try_clone().unwrap().eq([1, 2].iter().cloned()));
+        assert_eq!(c.nth(1), Some(2));
+        assert!(c.try_clone().is_none());
+    }
+
+    #[test]
+    fn last_resumed() {
+        let mut g = gen_iter!({
+            yield 1;
+        });
+        assert!(!g.last_resumed());
+
+        assert_eq!(g.next(), Some(1));
+        assert!(g.last_resumed());
+
+        assert_eq!(g.next(), None); // resumed and observed the completion
+        assert!(g.last_resumed());
+
+        assert_eq!(g.next(), None); // already done, not resumed
+        assert!(!g.last_resumed());
+        assert_eq!(g.next(), None);
+        assert!(!g.last_resumed());
+    }
 }
@@ -0,0 +1,129 @@
+use core::ops::Generator;
+use core::iter::{Iterator, DoubleEndedIterator, FusedIterator};
+use core::marker::Unpin;
+use alloc::collections::VecDeque;
+
+use GenIter;
+
+/// a [`GenIter`] iterating from both ends by buffering the yields,
+/// created by [`GenIter::buffered`].
+///
+/// `next` takes the buffered items first and resumes the generator only when the buffer is empty,
+/// `next_back` drains the whole generator into the buffer on its first call,
+/// so it needs memory for all the remaining items and never returns for an endless generator.
+#[derive(Clone, Debug)]
+pub struct GenIterBuffered<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    buf: VecDeque<T::Yield>,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// buffer the yields in a `VecDeque`, to implement `DoubleEndedIterator`
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter;
+    ///
+    /// let g = gen_iter!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     yield 3;
+    /// });
+    ///
+    /// assert_eq!(g.buffered().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn buffered(self) -> GenIterBuffered<T> {
+        GenIterBuffered {
+            iter: self,
+            buf: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> Iterator for GenIterBuffered<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.buf.pop_front() {
+            Some(y) => Some(y),
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.iter.done {
+            (self.buf.len(), Some(self.buf.len()))
+        } else {
+            (self.buf.len(), None)
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for GenIterBuffered<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buf.extend(&mut self.iter);
+        self.buf.pop_back()
+    }
+}
+
+impl<T> FusedIterator for GenIterBuffered<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn rev() {
+        let g = gen_iter!({
+            for i in 0..5 {
+                yield i;
+            }
+        });
+
+        assert!(g.buffered().rev().eq((0..5).rev()));
+    }
+
+    #[test]
+    fn both_ends() {
+        let resumes = Cell::new(0);
+        let mut g = gen_iter!({
+            for i in 0..5 {
+                resumes.set(resumes.get() + 1);
+                yield i;
+            }
+        }).buffered();
+
+        assert_eq!(g.next(), Some(0));
+        assert_eq!(resumes.get(), 1);
+        assert_eq!(g.size_hint(), (0, None));
+
+        assert_eq!(g.next_back(), Some(4));
+        assert_eq!(resumes.get(), 5);
+        assert_eq!(g.size_hint(), (3, Some(3)));
+
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.next_back(), Some(3));
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next_back(), None);
+    }
+}
@@ -0,0 +1,142 @@
+use core::ops::Generator;
+use core::iter::{Iterator, ExactSizeIterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a [`GenIter`] with a known number of yields, implementing `ExactSizeIterator`,
+/// created by [`gen_iter!`] with `len = n`.
+///
+/// the generator must yield exactly `len` items, yielding more or fewer is a contract violation,
+/// checked by debug assertions.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::gen_iter;
+///
+/// let mut g = gen_iter!(len = 3 {
+///     yield 'a';
+///     yield 'b';
+///     yield 'c';
+/// });
+///
+/// assert_eq!(g.len(), 3);
+/// g.next();
+/// assert_eq!(g.len(), 2);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct GenIterExact<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    remaining: usize,
+}
+
+impl<T> GenIterExact<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    pub fn new(gen: T, len: usize) -> Self {
+        GenIterExact {
+            iter: GenIter::new(gen),
+            remaining: len,
+        }
+    }
+}
+
+impl<T> Iterator for GenIterExact<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(y) => {
+                debug_assert!(self.remaining > 0, "generator yielded more items than its length");
+                self.remaining = self.remaining.saturating_sub(1);
+                Some(y)
+            },
+            None => {
+                debug_assert_eq!(self.remaining, 0, "generator yielded fewer items than its length");
+                None
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for GenIterExact<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+impl<T> FusedIterator for GenIterExact<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use super::GenIterExact;
+
+    #[test]
+    fn exact() {
+        let mut g = gen_iter!(len = 4 {
+            for i in 0..4 {
+                yield i;
+            }
+        });
+
+        for expected in (0..4).rev() {
+            assert!(g.next().is_some());
+            assert_eq!(g.len(), expected);
+        }
+        assert_eq!(g.next(), None);
+        assert_eq!(g.len(), 0);
+    }
+
+    #[test]
+    fn new_move() {
+        let s = "xy";
+        let g = GenIterExact::new(move || {
+            for c in s.chars() {
+                yield c;
+            }
+        }, 2);
+
+        assert_eq!(g.len(), 2);
+        assert!(g.eq(['x', 'y'].iter().cloned()));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "fewer items")]
+    fn too_few() {
+        let g = gen_iter!(len = 2 {
+            yield 1;
+        });
+
+        g.for_each(drop);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "more items")]
+    fn too_many() {
+        let g = gen_iter!(len = 1 move {
+            yield 1;
+            yield 2;
+        });
+
+        g.for_each(drop);
+    }
+}
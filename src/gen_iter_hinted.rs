@@ -0,0 +1,128 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a [`GenIter`] reporting a size hint given by the author of the generator,
+/// created by [`gen_iter!`] with `hint = (lower, upper)`.
+///
+/// the hint is advisory, it is not checked against the actual yields,
+/// it counts down by one for each yield and becomes `(0, Some(0))` when the generator is done.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::gen_iter;
+///
+/// let g = gen_iter!(hint = (3, Some(3)) {
+///     for i in 0..3 {
+///         yield i;
+///     }
+/// });
+///
+/// assert_eq!(g.size_hint(), (3, Some(3)));
+/// assert_eq!(g.collect::<Vec<_>>(), [0, 1, 2]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct GenIterHinted<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<T>,
+    lower: usize,
+    upper: Option<usize>,
+}
+
+impl<T> GenIterHinted<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    pub fn new(gen: T, lower: usize, upper: Option<usize>) -> Self {
+        GenIterHinted {
+            iter: GenIter::new(gen),
+            lower,
+            upper,
+        }
+    }
+}
+
+impl<T> Iterator for GenIterHinted<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(y) => {
+                self.lower = self.lower.saturating_sub(1);
+                self.upper = self.upper.map(|u| u.saturating_sub(1));
+                Some(y)
+            },
+            None => {
+                self.lower = 0;
+                self.upper = Some(0);
+                None
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.lower, self.upper)
+    }
+}
+
+impl<T> FusedIterator for GenIterHinted<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{}
+
+#[cfg(test)]
+mod tests {
+    use super::GenIterHinted;
+
+    #[test]
+    fn hint_counts_down() {
+        let mut g = gen_iter!(hint = (2, Some(4)) {
+            yield 1;
+            yield 2;
+            yield 3;
+        });
+
+        assert_eq!(g.size_hint(), (2, Some(4)));
+        assert_eq!(g.next(), Some(1));
+        assert_eq!(g.size_hint(), (1, Some(3)));
+        assert_eq!(g.next(), Some(2));
+        assert_eq!(g.next(), Some(3));
+        assert_eq!(g.size_hint(), (0, Some(1)));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn move_and_unbounded() {
+        let n = 3;
+        let g = gen_iter!(hint = (1, None) move {
+            for i in 0..n {
+                yield i;
+            }
+        });
+
+        assert_eq!(g.size_hint(), (1, None));
+        assert!(g.eq(0..3));
+    }
+
+    #[test]
+    fn new() {
+        let g = GenIterHinted::new(|| {
+            yield 'a';
+        }, 1, Some(1));
+
+        assert_eq!(g.size_hint(), (1, Some(1)));
+        assert!(g.eq(['a'].iter().cloned()));
+    }
+}
@@ -0,0 +1,213 @@
+use core::ops::{Generator, GeneratorState};
+use core::marker::{Unpin, PhantomData};
+use core::pin::Pin;
+use core::fmt;
+use core::iter::{self, Iterator, IntoIterator};
+
+/// `GenIterResume<G, A>` holds a generator `G` taking resume arguments of type `A`,
+/// or the return value of `G`.
+///
+/// it is driven by [`resume_with`](GenIterResume::resume_with) instead of `Iterator::next`,
+/// and like [`GenIterReturn`](crate::GenIterReturn) it is safe to resume after the generator is done.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::GenIterResume;
+///
+/// // push samples, get the running average
+/// let mut avg = GenIterResume::new(|mut sample: f64| {
+///     let (mut sum, mut count) = (0.0, 0.0);
+///     loop {
+///         sum += sample;
+///         count += 1.0;
+///         sample = yield sum / count;
+///     }
+/// });
+///
+/// assert_eq!(avg.resume_with(2.0), Some(2.0));
+/// assert_eq!(avg.resume_with(4.0), Some(3.0));
+/// assert_eq!(avg.resume_with(9.0), Some(5.0));
+/// ```
+pub struct GenIterResume<G: Generator<A> + Unpin, A> {
+    state: Result<G::Return, G>,
+    _arg: PhantomData<fn(A)>,
+}
+
+impl<G: Generator<A> + Unpin, A> GenIterResume<G, A> {
+    #[inline]
+    pub fn new(g: G) -> Self {
+        GenIterResume {
+            state: Err(g),
+            _arg: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.state.is_ok()
+    }
+
+    #[inline]
+    pub fn return_or_self(self) -> Result<G::Return, Self> {
+        match self.state {
+            Ok(r) => Ok(r),
+            Err(_) => Err(self),
+        }
+    }
+
+    /// resume the generator with `arg`, and get the next yield,
+    /// `None` if the generator is done.
+    #[inline]
+    pub fn resume_with(&mut self, arg: A) -> Option<G::Yield> {
+        match self.state {
+            Ok(_) => None,
+            Err(ref mut g) => match Pin::new(g).resume(arg) {
+                GeneratorState::Yielded(y) => Some(y),
+                GeneratorState::Complete(r) => {
+                    self.state = Ok(r);
+                    None
+                },
+            }
+        }
+    }
+
+    /// resume the generator once with each input, yielding the outputs,
+    /// until the generator is done or the inputs run out.
+    ///
+    /// the input of the resume completing the generator is consumed and lost.
+    /// the generator lives in the returned iterator, and is dropped with it, not when it yields `None`.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::GenIterResume;
+    ///
+    /// let doubler = GenIterResume::new(|mut x: i32| {
+    ///     loop {
+    ///         x = yield x * 2;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(doubler.resume_each(vec![1, 2, 3]).collect::<Vec<_>>(), [2, 4, 6]);
+    /// ```
+    pub fn resume_each<I>(mut self, inputs: I) -> impl Iterator<Item = G::Yield>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut inputs = inputs.into_iter();
+        iter::from_fn(move || {
+            let arg = inputs.next()?;
+            self.resume_with(arg)
+        }).fuse()
+    }
+}
+
+impl<G: Generator<A> + Unpin, A> From<G> for GenIterResume<G, A> {
+    #[inline]
+    fn from(g: G) -> Self {
+        GenIterResume::new(g)
+    }
+}
+
+impl<G: Generator<A> + Unpin + Clone, A> Clone for GenIterResume<G, A>
+where
+    G::Return: Clone,
+{
+    fn clone(&self) -> Self {
+        GenIterResume {
+            state: self.state.clone(),
+            _arg: PhantomData,
+        }
+    }
+}
+
+impl<G: Generator<A> + Unpin + fmt::Debug, A> fmt::Debug for GenIterResume<G, A>
+where
+    G::Return: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GenIterResume").field(&self.state).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use super::GenIterResume;
+
+    #[test]
+    fn running_average() {
+        let mut avg = GenIterResume::new(|mut sample: f64| {
+            let (mut sum, mut count) = (0.0, 0.0);
+            loop {
+                sum += sample;
+                count += 1.0;
+                sample = yield sum / count;
+            }
+        });
+
+        let expected = [1.0, 2.0, 3.0, 4.0, 5.0];
+        for (i, &e) in expected.iter().enumerate() {
+            let sample = (2 * i + 1) as f64;
+            assert_eq!(avg.resume_with(sample), Some(e));
+        }
+        assert!(!avg.is_done());
+    }
+
+    #[test]
+    fn done_and_return() {
+        let mut g = GenIterResume::new(|a: u32| {
+            let b = yield a * 2;
+            a + b
+        });
+
+        assert_eq!(g.resume_with(3), Some(6));
+        assert_eq!(g.resume_with(4), None);
+        assert!(g.is_done());
+        assert_eq!(g.resume_with(5), None);
+        assert_eq!(g.return_or_self().ok(), Some(7));
+    }
+
+    #[test]
+    fn resume_each_inputs_run_out() {
+        let resumes = Cell::new(0);
+        let g = GenIterResume::new(|mut x: u32| {
+            let mut total = 0;
+            loop {
+                resumes.set(resumes.get() + 1);
+                total += x;
+                x = yield total;
+            }
+        });
+
+        assert!(g.resume_each([1, 2, 3].iter().cloned()).eq([1, 3, 6].iter().cloned()));
+        assert_eq!(resumes.get(), 3);
+    }
+
+    #[test]
+    fn resume_each_generator_done() {
+        let g = GenIterResume::new(|a: u32| {
+            let b = yield a;
+            yield a + b;
+        });
+
+        let taken = Cell::new(0);
+        let inputs = (1..10).inspect(|_| taken.set(taken.get() + 1));
+        let mut outputs = g.resume_each(inputs);
+
+        assert!(outputs.by_ref().eq([1, 3].iter().cloned()));
+        assert_eq!(outputs.next(), None);
+        assert_eq!(taken.get(), 3);
+    }
+
+    #[test]
+    fn not_done() {
+        let mut g: GenIterResume<_, u8> = (|a: u8| {
+            yield a;
+        }).into();
+
+        assert_eq!(g.resume_with(1), Some(1));
+        assert!(g.return_or_self().is_err());
+    }
+}
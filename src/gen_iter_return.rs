@@ -1,47 +1,255 @@
 use core::ops::{Generator, GeneratorState};
-use core::iter::{Iterator, FusedIterator};
+use core::iter::{self, Iterator, IntoIterator, FusedIterator};
 use core::marker::Unpin;
 use core::pin::Pin;
+use core::mem;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 
 /// `GenIterReturn<G>` holds a generator `G` or the return value of `G`,
 /// `&mut GenIterReturn<G>` acts as an iterator.
 /// 
 /// Differences with `GenIter<G>`:
 /// 1. able to get return value of a generator
-/// 2. safe to call `next()` after generator is done without panic
-/// 3. maybe less efficient than `GenIter<G>`
+/// 2. maybe less efficient than `GenIter<G>`
+///
+/// like `GenIter<G>`, it is safe to call `next()` after generator is done without panic.
+pub struct GenIterReturn<G: Generator + Unpin>(pub(crate) State<G>);
+
+/// the state of a [`GenIterReturn`]
 #[derive(Copy, Clone, Debug)]
-pub struct GenIterReturn<G: Generator + Unpin>(Result<G::Return, G>);
+pub(crate) enum State<G: Generator> {
+    /// the generator is not done
+    Running(G),
+    /// the generator is done and returned this value
+    Returned(G::Return),
+    /// the generator is done and the return value was taken by `take_return`
+    Taken,
+}
+
+/// the state of a [`GenIterReturn`] taken apart by [`GenIterReturn::into_parts`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GenState<R, G> {
+    /// the generator is done and returned this value
+    Done(R),
+    /// the generator is not done
+    Running(G),
+    /// the generator is done and the return value was taken by `take_return`
+    Taken,
+}
 
 impl<G: Generator + Unpin> GenIterReturn<G> {
     #[inline]
     pub fn new(g: G) -> Self {
-        GenIterReturn(Err(g))
+        GenIterReturn(State::Running(g))
+    }
+
+    /// create the generator by calling `f`,
+    /// to wrap a function building generators from runtime parameters.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnOnce() -> G,
+    {
+        GenIterReturn::new(f())
     }
 
     #[inline]
     pub fn is_done(&self) -> bool {
-        self.0.is_ok()
+        match self.0 {
+            State::Running(_) => false,
+            State::Returned(_) | State::Taken => true,
+        }
     }
 
+    /// the return value, or `self` if the generator is not done or the value was taken
     #[inline]
     pub fn return_or_self(self) -> Result<G::Return, Self> {
         match self.0 {
-            Ok(r) => Ok(r),
-            Err(_) => Err(self),
+            State::Returned(r) => Ok(r),
+            _ => Err(self),
+        }
+    }
+
+    /// a reference to the return value, `None` if the generator is not done
+    #[inline]
+    pub fn return_value(&self) -> Option<&G::Return> {
+        match self.0 {
+            State::Returned(ref r) => Some(r),
+            _ => None,
         }
     }
+
+    /// a mutable reference to the return value, `None` if the generator is not done
+    #[inline]
+    pub fn return_value_mut(&mut self) -> Option<&mut G::Return> {
+        match self.0 {
+            State::Returned(ref mut r) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// a nameable iterator over `self`, the same as `&mut self`
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     return "done";
+    /// });
+    ///
+    /// assert_eq!(g.as_iter().collect::<Vec<_>>(), [1]);
+    /// assert_eq!(g.return_or_self().ok(), Some("done"));
+    /// ```
+    #[inline]
+    pub fn as_iter(&mut self) -> GenIterReturnRef<'_, G> {
+        GenIterReturnRef(self)
+    }
+
+    /// move the return value out, leaving `self` done without a return value,
+    /// `None` if the generator is not done or the value was already taken.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     return String::from("done");
+    /// });
+    ///
+    /// assert_eq!(g.take_return(), None);
+    /// (&mut g).for_each(drop);
+    /// assert_eq!(g.take_return(), Some(String::from("done")));
+    /// assert_eq!(g.take_return(), None);
+    /// assert!(g.is_done());
+    /// ```
+    #[inline]
+    pub fn take_return(&mut self) -> Option<G::Return> {
+        match self.0 {
+            State::Returned(_) => match mem::replace(&mut self.0, State::Taken) {
+                State::Returned(r) => Some(r),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+
+    /// take `self` apart into the generator or its return value
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::{gen_iter_return, GenState};
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     return "done";
+    /// });
+    /// (&mut g).for_each(drop);
+    ///
+    /// match g.into_parts() {
+    ///     GenState::Done(r) => assert_eq!(r, "done"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> GenState<G::Return, G> {
+        match self.0 {
+            State::Running(g) => GenState::Running(g),
+            State::Returned(r) => GenState::Done(r),
+            State::Taken => GenState::Taken,
+        }
+    }
+
+    /// a by-value iterator yielding the yields of the generator,
+    /// then its return value converted into the yield type.
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let g = gen_iter_return!({
+    ///     yield 1;
+    ///     yield 2;
+    ///     return 3;
+    /// });
+    ///
+    /// assert_eq!(g.into_iter_with_return().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    pub fn into_iter_with_return(self) -> impl Iterator<Item = G::Yield>
+    where
+        G::Return: Into<G::Yield>,
+    {
+        let mut g = Some(self);
+        iter::from_fn(move || {
+            let mut it = g.as_mut()?;
+            if let Some(y) = it.next() {
+                return Some(y);
+            }
+            g.take()?.return_or_self().ok().map(Into::into)
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G: Generator> GenIterReturn<Pin<Box<G>>> {
+    /// pin the generator on the heap, so it does not need to be `Unpin`,
+    /// like a `static` generator holding borrows of its own locals across yields.
+    #[inline]
+    pub fn boxed(g: G) -> Self {
+        GenIterReturn::new(Box::pin(g))
+    }
+}
+
+impl<G: Generator + Unpin + Clone> Clone for GenIterReturn<G>
+where
+    G::Return: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        GenIterReturn(self.0.clone())
+    }
+}
+
+impl<G: Generator + Unpin + Copy> Copy for GenIterReturn<G>
+where
+    G::Return: Copy,
+{}
+
+impl<G: Generator + Unpin + fmt::Debug> fmt::Debug for GenIterReturn<G>
+where
+    G::Return: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GenIterReturn").field(&self.0).finish()
+    }
 }
 
-/// Force use `&mut g` as iterator to prevent the code below,
-/// in which return value cannot be got.
+/// iterate `&mut g` to keep the return value in `g` after the loop,
+/// a by-value `for v in g` uses `IntoIterator`, which discards the return value.
+/// ```
+/// # #![feature(generators)]
+/// # use gen_iter::gen_iter_return;
+/// let mut g = gen_iter_return!({ yield 1; return "done"; });
+/// for _ in &mut g {}
+/// assert_eq!(g.return_or_self().ok(), Some("done"));
+/// ```
+///
+/// so the return value cannot be got after a by-value loop:
 /// ```compile_fail
 /// // !!INVALID CODE!!
 /// # #![feature(generators)]
 /// # use gen_iter::gen_iter_return;
-/// let mut g = gen_iter_return!({ yield 1; return "done"; });
-/// for v in g {} // invalid, because `GenIterReturn<G>` is not `Iterator`
-/// let ret = g.return_or_self(); // g is dropped after for loop
+/// let g = gen_iter_return!({ yield 1; return "done"; });
+/// for _ in g {} // `g` is moved into the loop
+/// let ret = g.return_or_self(); // error: use of moved value `g`
 /// ```
 impl<G: Generator + Unpin> Iterator for &mut GenIterReturn<G> {
     type Item = G::Yield;
@@ -49,21 +257,166 @@ impl<G: Generator + Unpin> Iterator for &mut GenIterReturn<G> {
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         match self.0 {
-            Ok(_) => None,
-            Err(ref mut g) => match Pin::new(g).resume(()) {
+            State::Running(ref mut g) => match Pin::new(g).resume(()) {
                 GeneratorState::Yielded(y) => Some(y),
                 GeneratorState::Complete(r) => {
-                    self.0 = Ok(r);
+                    self.0 = State::Returned(r);
                     None
                 },
-            }
+            },
+            State::Returned(_) | State::Taken => None,
+        }
+    }
+
+    /// resume the generator until it is done, counting the yields,
+    /// the return value is kept.
+    #[inline]
+    fn count(self) -> usize {
+        let mut n = 0;
+        if let State::Running(ref mut g) = self.0 {
+            let r = loop {
+                match Pin::new(&mut *g).resume(()) {
+                    GeneratorState::Yielded(_) => n += 1,
+                    GeneratorState::Complete(r) => break r,
+                }
+            };
+            self.0 = State::Returned(r);
+        }
+        n
+    }
+
+    /// resume the generator until it is done, keeping only the latest yield,
+    /// the return value is kept.
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        let mut last = None;
+        if let State::Running(ref mut g) = self.0 {
+            let r = loop {
+                match Pin::new(&mut *g).resume(()) {
+                    GeneratorState::Yielded(y) => last = Some(y),
+                    GeneratorState::Complete(r) => break r,
+                }
+            };
+            self.0 = State::Returned(r);
+        }
+        last
+    }
+
+    /// resume the generator until it is done in one loop,
+    /// the return value is kept.
+    #[inline]
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        if let State::Running(ref mut g) = self.0 {
+            let r = loop {
+                match Pin::new(&mut *g).resume(()) {
+                    GeneratorState::Yielded(y) => f(y),
+                    GeneratorState::Complete(r) => break r,
+                }
+            };
+            self.0 = State::Returned(r);
+        }
+    }
+
+    /// resume the generator in one loop, dropping the first `n` yields,
+    /// the return value is kept if the generator is done before the `n`th yield.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let mut skip = n;
+        if let State::Running(ref mut g) = self.0 {
+            let r = loop {
+                match Pin::new(&mut *g).resume(()) {
+                    GeneratorState::Yielded(y) => {
+                        if skip == 0 {
+                            return Some(y);
+                        }
+                        skip -= 1;
+                    },
+                    GeneratorState::Complete(r) => break r,
+                }
+            };
+            self.0 = State::Returned(r);
         }
+        None
     }
 }
 
 /// `GenIterReturn<G>` satisfies the trait `FusedIterator`
 impl<G: Generator + Unpin> FusedIterator for &mut GenIterReturn<G> {}
 
+/// an iterator borrowing a [`GenIterReturn`], created by [`GenIterReturn::as_iter`].
+///
+/// it is a named type for `&mut GenIterReturn<G>`, to be stored in a field or used in a signature.
+pub struct GenIterReturnRef<'a, G: Generator + Unpin + 'a>(&'a mut GenIterReturn<G>);
+
+impl<'a, G: Generator + Unpin + fmt::Debug> fmt::Debug for GenIterReturnRef<'a, G>
+where
+    G::Return: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GenIterReturnRef").field(&self.0).finish()
+    }
+}
+
+impl<'a, G: Generator + Unpin> Iterator for GenIterReturnRef<'a, G> {
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (&mut *self.0).next()
+    }
+}
+
+impl<'a, G: Generator + Unpin> FusedIterator for GenIterReturnRef<'a, G> {}
+
+/// an iterator over the yields of a [`GenIterReturn`] which discards the return value,
+/// created by `GenIterReturn::into_iter`.
+pub struct GenIterReturnIter<G: Generator + Unpin>(GenIterReturn<G>);
+
+impl<G: Generator + Unpin + Clone> Clone for GenIterReturnIter<G>
+where
+    G::Return: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        GenIterReturnIter(self.0.clone())
+    }
+}
+
+impl<G: Generator + Unpin + fmt::Debug> fmt::Debug for GenIterReturnIter<G>
+where
+    G::Return: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GenIterReturnIter").field(&self.0).finish()
+    }
+}
+
+impl<G: Generator + Unpin> Iterator for GenIterReturnIter<G> {
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (&mut self.0).next()
+    }
+}
+
+impl<G: Generator + Unpin> FusedIterator for GenIterReturnIter<G> {}
+
+/// iterate by value for a plain `for` loop, the return value is discarded,
+/// iterate `&mut g` to keep it.
+impl<G: Generator + Unpin> IntoIterator for GenIterReturn<G> {
+    type Item = G::Yield;
+    type IntoIter = GenIterReturnIter<G>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        GenIterReturnIter(self)
+    }
+}
+
 impl<G: Generator + Unpin> From<G> for GenIterReturn<G> {
     #[inline]
     fn from(g: G) -> Self {
@@ -98,9 +451,50 @@ macro_rules! gen_iter_return {
     }
 }
 
+/// macro to create a [`GenIterReturn`] over a generator which can use the `?` operator,
+/// the generator returns `Ok(())` at the end of the block, or the first `Err` hit by `?`.
+///
+/// the items are yielded as usual, and the iteration stops at the error,
+/// which is got by `return_or_self`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use std::num::ParseIntError;
+/// use gen_iter::gen_try_iter;
+///
+/// let mut g = gen_try_iter!({
+///     for s in "1 2 x 4".split(' ') {
+///         let n: u32 = s.parse()?;
+///         yield n;
+///     }
+/// });
+///
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [1, 2]);
+/// let r: Result<(), ParseIntError> = g.return_or_self().ok().unwrap();
+/// assert!(r.is_err());
+/// ```
+#[macro_export]
+macro_rules! gen_try_iter {
+    ($block: block) => {
+        $crate::GenIterReturn::new(|| {
+            $block;
+            Ok(())
+        })
+    };
+    (move $block: block) => {
+        $crate::GenIterReturn::new(move || {
+            $block;
+            Ok(())
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GenIterReturn;
+    use core::ops::Generator;
+    use core::marker::Unpin;
+    use super::{GenIterReturn, GenState};
 
     /// test `new` and all instance method,
     /// and show that it won't panic when call `next()` even exhausted.
@@ -141,6 +535,273 @@ mod tests {
         assert_eq!(g.return_or_self().ok(), Some("done"));
     }
 
+    #[test]
+    fn return_value() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            return 10;
+        });
+
+        assert_eq!(g.return_value(), None);
+        assert_eq!(g.return_value_mut(), None);
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!(g.return_value(), None);
+
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_value(), Some(&10));
+
+        if let Some(r) = g.return_value_mut() {
+            *r += 1;
+        }
+        assert_eq!(g.return_value(), Some(&11));
+        assert_eq!(g.return_or_self().ok(), Some(11));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        let mut g = GenIterReturn::boxed(static || {
+            let v = [1, 2, 3];
+            let mut sum = 0;
+            for x in &v {
+                sum += x;
+                yield sum;
+            }
+            v.len()
+        });
+
+        assert!((&mut g).eq([1, 3, 6].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(3));
+    }
+
+    #[test]
+    fn into_iter_with_return() {
+        let g = gen_iter_return!({
+            let mut total = 0;
+            for i in 1..4 {
+                total += i;
+                yield i;
+            }
+            total
+        });
+
+        let mut it = g.into_iter_with_return();
+        assert!(it.by_ref().eq([1, 2, 3, 6].iter().cloned()));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn into_iter_with_return_convert() {
+        let g = gen_iter_return!({
+            yield 1u64;
+            return 2u32;
+        });
+
+        assert!(g.into_iter_with_return().eq([1u64, 2].iter().cloned()));
+    }
+
+    #[test]
+    fn from_fn() {
+        let limit = 2;
+        let mut g = GenIterReturn::from_fn(|| move || {
+            for i in 0..limit {
+                yield i;
+            }
+            limit * 10
+        });
+
+        assert!((&mut g).eq(0..2));
+        assert_eq!(g.return_or_self().ok(), Some(20));
+    }
+
+    #[test]
+    fn gen_try_iter() {
+        use core::num::ParseIntError;
+
+        fn parse(s: &'static str) -> GenIterReturn<impl Generator<Yield = u8, Return = Result<(), ParseIntError>> + Unpin> {
+            gen_try_iter!(move {
+                for part in s.split(',') {
+                    yield part.parse()?;
+                }
+            })
+        }
+
+        let mut ok = parse("1,2,3");
+        assert!((&mut ok).eq([1, 2, 3].iter().cloned()));
+        assert_eq!(ok.return_or_self().ok(), Some(Ok(())));
+
+        let mut err = parse("1,300,3");
+        assert!((&mut err).eq([1].iter().cloned()));
+        assert!((&mut err).next().is_none());
+        match err.return_or_self() {
+            Ok(Err(_)) => (),
+            _ => panic!("the error should be returned"),
+        }
+    }
+
+    #[test]
+    fn take_return() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            return 'r';
+        });
+
+        assert_eq!(g.take_return(), None);
+        assert!(!g.is_done());
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!(g.take_return(), None);
+        assert_eq!((&mut g).next(), None);
+
+        assert!(g.is_done());
+        assert_eq!(g.take_return(), Some('r'));
+        assert!(g.is_done());
+        assert_eq!(g.take_return(), None);
+        assert_eq!(g.return_value(), None);
+        assert_eq!((&mut g).next(), None);
+        assert!(g.return_or_self().is_err());
+    }
+
+    #[test]
+    fn count_and_last() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            yield 3;
+            "done"
+        });
+        (&mut g).next();
+
+        assert_eq!((&mut g).count(), 2);
+        assert!(g.is_done());
+        assert_eq!((&mut g).count(), 0);
+        assert_eq!(g.return_value(), Some(&"done"));
+
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            "done"
+        });
+
+        assert_eq!((&mut g).last(), Some(2));
+        assert!(g.is_done());
+        assert_eq!((&mut g).last(), None);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
+    #[test]
+    fn for_each() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            yield 3;
+            return "done";
+        });
+        (&mut g).next();
+
+        let mut sum = 0;
+        (&mut g).for_each(|y| sum += y);
+        assert_eq!(sum, 5);
+        assert!(g.is_done());
+
+        let mut calls = 0;
+        (&mut g).for_each(|_| calls += 1);
+        assert_eq!(calls, 0);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
+    #[test]
+    fn nth() {
+        let mut g = gen_iter_return!({
+            for i in 0..5 {
+                yield i;
+            }
+            "done"
+        });
+
+        assert_eq!((&mut g).nth(1), Some(1));
+        assert_eq!((&mut g).nth(2), Some(4));
+        assert!(!g.is_done());
+        assert_eq!((&mut g).nth(3), None);
+        assert_eq!(g.return_value(), Some(&"done"));
+
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            "done"
+        });
+
+        assert_eq!((&mut g).nth(10), None);
+        assert!(g.is_done());
+        assert_eq!((&mut g).nth(1), None);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
+    #[test]
+    fn into_parts() {
+        let g = gen_iter_return!({
+            yield 1;
+            return 2;
+        });
+
+        let mut g = match g.into_parts() {
+            GenState::Running(g) => GenIterReturn::new(g),
+            _ => panic!("not resumed yet"),
+        };
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!((&mut g).next(), None);
+
+        assert!(matches!(g.into_parts(), GenState::Done(2)));
+
+        let mut g = gen_iter_return!({
+            yield 1;
+            return 2;
+        });
+        (&mut g).for_each(drop);
+        assert_eq!(g.take_return(), Some(2));
+        assert!(matches!(g.into_parts(), GenState::Taken));
+    }
+
+    #[test]
+    fn into_iter_by_value() {
+        let g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            return "discarded";
+        });
+
+        let mut sum = 0;
+        for y in g {
+            sum += y;
+        }
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn as_iter() {
+        use super::GenIterReturnRef;
+
+        fn take_two<G: Generator<Yield = i32> + Unpin>(it: &mut GenIterReturnRef<G>) -> i32 {
+            it.next().unwrap_or(0) + it.next().unwrap_or(0)
+        }
+
+        let mut g = gen_iter_return!({
+            for i in 1..6 {
+                yield i;
+            }
+            "done"
+        });
+
+        {
+            let mut it: GenIterReturnRef<_> = g.as_iter();
+            assert_eq!(take_two(&mut it), 3);
+            assert_eq!(take_two(&mut it), 7);
+            assert_eq!(take_two(&mut it), 5);
+            assert_eq!(it.next(), None);
+        }
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
     /// normal usage using macro `gen_iter_return`
     #[test]
     fn macro_usage() {
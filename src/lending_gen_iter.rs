@@ -0,0 +1,136 @@
+use core::ops::{Generator, GeneratorState};
+use core::marker::Unpin;
+use core::pin::Pin;
+
+/// a lending iterator over a generator yielding pointers to its own state,
+/// each item borrows the `LendingGenIter` until the next call to [`next`](LendingGenIter::next).
+///
+/// the `Yield` of a generator cannot borrow from the generator itself,
+/// so the generator yields `*const U` and `next` turns it into a `&U`.
+/// the unstable `LendingIterator` trait is not implemented, use `while let` instead of `for`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::LendingGenIter;
+///
+/// // SAFETY: `line` lives in the generator until the generator is resumed again
+/// let mut lines = unsafe {
+///     LendingGenIter::new(|| {
+///         let mut line = [0u8; 3];
+///         for c in b'a'..b'd' {
+///             line = [c; 3];
+///             yield &line as *const [u8; 3];
+///         }
+///     })
+/// };
+///
+/// assert_eq!(lines.next(), Some(&*b"aaa"));
+/// assert_eq!(lines.next(), Some(&*b"bbb"));
+/// assert_eq!(lines.next(), Some(&*b"ccc"));
+/// assert_eq!(lines.next(), None);
+/// ```
+///
+/// the item cannot be kept after the next call:
+/// ```compile_fail
+/// #![feature(generators)]
+///
+/// use gen_iter::LendingGenIter;
+///
+/// let mut lines = unsafe {
+///     LendingGenIter::new(|| {
+///         let line = [0u8; 3];
+///         yield &line as *const [u8; 3];
+///     })
+/// };
+///
+/// let first = lines.next();
+/// lines.next();
+/// drop(first);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LendingGenIter<T> {
+    gen: T,
+    done: bool,
+}
+
+impl<T, U> LendingGenIter<T>
+where
+    T: Generator<Yield = *const U, Return = ()> + Unpin,
+    U: ?Sized,
+{
+    /// # Safety
+    ///
+    /// every pointer yielded by `gen` must be valid for reads,
+    /// until `gen` is resumed again, moved or dropped.
+    #[inline]
+    pub unsafe fn new(gen: T) -> Self {
+        LendingGenIter {
+            gen,
+            done: false,
+        }
+    }
+
+    /// resume the generator, lending the pointee of its yield until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&U> {
+        if self.done {
+            return None;
+        }
+        match Pin::new(&mut self.gen).resume(()) {
+            // SAFETY: the generator cannot be resumed, moved or dropped
+            // while `self` is borrowed, see `new`
+            GeneratorState::Yielded(p) => Some(unsafe { &*p }),
+            GeneratorState::Complete(()) => {
+                self.done = true;
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LendingGenIter;
+
+    #[test]
+    fn borrow_buffer() {
+        let src: &[u8] = b"hello world, lending";
+        let mut chunks = unsafe {
+            LendingGenIter::new(move || {
+                let mut buf = [0u8; 8];
+                for chunk in src.chunks(buf.len()) {
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    yield &buf[..chunk.len()] as *const [u8];
+                }
+            })
+        };
+
+        assert_eq!(chunks.next(), Some(&b"hello wo"[..]));
+        assert_eq!(chunks.next(), Some(&b"rld, len"[..]));
+        assert_eq!(chunks.next(), Some(&b"ding"[..]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn while_let() {
+        let mut g = unsafe {
+            LendingGenIter::new(|| {
+                let mut s = [0u32; 1];
+                for i in 1..4 {
+                    s[0] += i;
+                    yield &s[0] as *const u32;
+                }
+            })
+        };
+
+        let mut sums = [0; 3];
+        let mut n = 0;
+        while let Some(&sum) = g.next() {
+            sums[n] = sum;
+            n += 1;
+        }
+        assert_eq!(sums, [1, 3, 6]);
+    }
+}
@@ -55,12 +55,110 @@
 //! println!("generator is_done={}", g.is_done()); // true
 //! println!("generator returns {}", g.return_or_self().ok().unwrap()); // "done"
 //! ```
+//!
+//! ## [`GenIterResume`]
+//! [`GenIterResume`] drives a [`Generator<A>`](core::ops::Generator) taking resume arguments of type `A`,
+//! each call to [`resume_with`](GenIterResume::resume_with) passes a value in and gets the next yield out.
+//!
+//! ```
+//! #![feature(generators)]
+//!
+//! use gen_iter::GenIterResume;
+//!
+//! let mut sum = GenIterResume::new(|mut x: i32| {
+//!     let mut total = 0;
+//!     loop {
+//!         total += x;
+//!         x = yield total;
+//!     }
+//! });
+//!
+//! println!("{:?}", sum.resume_with(1)); // Some(1)
+//! println!("{:?}", sum.resume_with(2)); // Some(3)
+//! ```
+//!
+//! ## features
+//! * `alloc`: items that need heap allocation, like `pascals_triangle` and `primes`
+//! * `std`: items that need the standard library, like `GenIter::timestamped`, implies `alloc`
+//! * `stream`: `GenStream` to use a generator as a `futures_core::Stream`
+//! * `rand`: generators using a random number generator, like `random_walk`
 
 #![no_std]
-#![feature(generators, generator_trait)]
+#![feature(generators, generator_trait, try_trait_v2)]
+#![cfg_attr(test, feature(generator_clone))]
+
+#[cfg(any(feature = "alloc", all(test, feature = "stream")))]
+#[cfg_attr(feature = "alloc", macro_use)]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "stream")]
+extern crate futures_core;
+
+#[cfg(all(test, feature = "stream"))]
+extern crate futures;
+
+#[cfg(feature = "rand")]
+extern crate rand;
 
+#[macro_use]
 mod gen_iter;
 pub use gen_iter::*;
 
+#[macro_use]
 mod gen_iter_return;
 pub use gen_iter_return::*;
+
+mod gen_iter_hinted;
+pub use gen_iter_hinted::*;
+
+mod gen_iter_exact;
+pub use gen_iter_exact::*;
+
+#[cfg(feature = "alloc")]
+mod gen_iter_buffered;
+#[cfg(feature = "alloc")]
+pub use gen_iter_buffered::*;
+
+mod gen_iter_resume;
+pub use gen_iter_resume::*;
+
+mod optional_gen_iter;
+pub use optional_gen_iter::*;
+
+mod non_empty_gen_iter;
+pub use non_empty_gen_iter::*;
+
+mod restartable_gen_iter;
+pub use restartable_gen_iter::*;
+
+mod tagged_item;
+pub use tagged_item::*;
+
+mod lending_gen_iter;
+pub use lending_gen_iter::*;
+
+mod peekable_return;
+pub use peekable_return::*;
+
+#[cfg(feature = "alloc")]
+mod assert_yields;
+#[cfg(feature = "alloc")]
+pub use assert_yields::*;
+
+mod adapters;
+pub use adapters::*;
+
+mod sources;
+pub use sources::*;
+
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use stream::*;
+
+mod consumers;
+#[cfg(feature = "alloc")]
+pub use consumers::*;
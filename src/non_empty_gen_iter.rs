@@ -0,0 +1,106 @@
+use core::ops::Generator;
+use core::iter::{self, Iterator, IntoIterator, Chain, Once};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a [`GenIter`] with at least one item, its first item is kept apart.
+///
+/// the guarantee holds until it is turned into an iterator,
+/// so it implements `IntoIterator`, yielding the first item then the rest.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::{gen_iter, NonEmptyGenIter};
+///
+/// let g = NonEmptyGenIter::first_then(3, gen_iter!({
+///     yield 1;
+///     yield 2;
+/// }));
+///
+/// assert_eq!(*g.head(), 3);
+/// assert_eq!(g.into_iter().collect::<Vec<_>>(), [3, 1, 2]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NonEmptyGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    first: T::Yield,
+    rest: GenIter<T>,
+}
+
+impl<T> NonEmptyGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// the items `first` then the yields of `rest`
+    #[inline]
+    pub fn first_then(first: T::Yield, rest: GenIter<T>) -> Self {
+        NonEmptyGenIter { first, rest }
+    }
+
+    /// the first item
+    #[inline]
+    pub fn head(&self) -> &T::Yield {
+        &self.first
+    }
+
+    /// the first item and the rest
+    #[inline]
+    pub fn into_parts(self) -> (T::Yield, GenIter<T>) {
+        (self.first, self.rest)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+    type IntoIter = Chain<Once<T::Yield>, GenIter<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.first).chain(self.rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NonEmptyGenIter;
+
+    #[test]
+    fn head_then_iterate() {
+        let g = NonEmptyGenIter::first_then(0, gen_iter!({
+            yield 1;
+            yield 2;
+        }));
+
+        assert_eq!(*g.head(), 0);
+        assert!(g.into_iter().eq(0..3));
+    }
+
+    #[test]
+    fn empty_rest() {
+        let g = NonEmptyGenIter::first_then('a', gen_iter!({
+            if false {
+                yield 'b';
+            }
+        }));
+
+        assert!(g.into_iter().eq(['a'].iter().cloned()));
+    }
+
+    #[test]
+    fn seeded_reduction() {
+        let g = NonEmptyGenIter::first_then(4, gen_iter!({
+            yield 9;
+            yield 2;
+        }));
+
+        let (first, rest) = g.into_parts();
+        assert_eq!(rest.fold(first, core::cmp::max), 9);
+    }
+}
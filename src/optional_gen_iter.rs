@@ -0,0 +1,111 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a slot that may hold a [`GenIter`], iterating an empty slot yields nothing.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::{gen_iter, OptionalGenIter};
+///
+/// let mut slot = OptionalGenIter::default();
+/// assert_eq!(slot.next(), None);
+///
+/// slot.set(gen_iter!({ yield 1; }));
+/// assert_eq!(slot.next(), Some(1));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct OptionalGenIter<T>(pub Option<GenIter<T>>)
+where
+    T: Generator<Return = ()> + Unpin;
+
+impl<T> OptionalGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    /// put `g` in the slot, dropping the previous generator
+    #[inline]
+    pub fn set(&mut self, g: GenIter<T>) {
+        self.0 = Some(g);
+    }
+
+    /// empty the slot, dropping the generator
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl<T> Default for OptionalGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    fn default() -> Self {
+        OptionalGenIter(None)
+    }
+}
+
+impl<T> From<GenIter<T>> for OptionalGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    fn from(g: GenIter<T>) -> Self {
+        OptionalGenIter(Some(g))
+    }
+}
+
+impl<T> Iterator for OptionalGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Generator;
+    use GenIter;
+    use super::OptionalGenIter;
+
+    fn counter(n: u32) -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+        gen_iter!(move {
+            for i in 0..n {
+                yield i;
+            }
+        })
+    }
+
+    #[test]
+    fn empty_set_clear() {
+        let mut slot = OptionalGenIter::default();
+        assert_eq!(slot.next(), None);
+
+        slot.set(counter(2));
+        assert_eq!(slot.next(), Some(0));
+        assert_eq!(slot.next(), Some(1));
+        assert_eq!(slot.next(), None);
+        assert_eq!(slot.next(), None);
+
+        slot.set(counter(3));
+        assert_eq!(slot.next(), Some(0));
+        slot.clear();
+        assert!(slot.0.is_none());
+        assert_eq!(slot.next(), None);
+    }
+
+    #[test]
+    fn from_gen_iter() {
+        let slot = OptionalGenIter::from(counter(3));
+        assert!(slot.eq(0..3));
+    }
+}
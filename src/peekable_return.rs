@@ -0,0 +1,171 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+use core::fmt;
+
+use GenIterReturn;
+
+/// a [`GenIterReturn`] able to peek the next yield, created by [`GenIterReturn::peekable_return`].
+///
+/// like `GenIterReturn<G>`, `&mut PeekableReturn<G>` acts as an iterator,
+/// and the return value is kept after the iteration.
+pub struct PeekableReturn<G: Generator + Unpin> {
+    inner: GenIterReturn<G>,
+    peeked: Option<G::Yield>,
+}
+
+impl<G: Generator + Unpin> GenIterReturn<G> {
+    /// create a [`PeekableReturn`]
+    ///
+    /// ```
+    /// #![feature(generators)]
+    ///
+    /// use gen_iter::gen_iter_return;
+    ///
+    /// let mut g = gen_iter_return!({
+    ///     yield 1;
+    ///     return "done";
+    /// }).peekable_return();
+    ///
+    /// assert_eq!(g.peek(), Some(&1));
+    /// assert_eq!((&mut g).next(), Some(1));
+    /// assert_eq!(g.peek(), None);
+    /// assert_eq!(g.return_or_self().ok(), Some("done"));
+    /// ```
+    #[inline]
+    pub fn peekable_return(self) -> PeekableReturn<G> {
+        PeekableReturn {
+            inner: self,
+            peeked: None,
+        }
+    }
+}
+
+impl<G: Generator + Unpin> PeekableReturn<G> {
+    /// a reference to the next yield without consuming it,
+    /// resuming the generator if no yield is buffered.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&G::Yield> {
+        if self.peeked.is_none() {
+            self.peeked = (&mut self.inner).next();
+        }
+        self.peeked.as_ref()
+    }
+
+    /// whether the generator is done and no yield is buffered.
+    ///
+    /// peeking the last yield does not complete the generator,
+    /// it is done after the next `peek()` or `next()` returns `None`.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.peeked.is_none() && self.inner.is_done()
+    }
+
+    /// the return value, or `self` if the generator is not done or a yield is buffered
+    #[inline]
+    pub fn return_or_self(self) -> Result<G::Return, Self> {
+        if self.peeked.is_some() {
+            return Err(self);
+        }
+        self.inner.return_or_self().map_err(GenIterReturn::peekable_return)
+    }
+
+    /// a reference to the return value, `None` if the generator is not done or a yield is buffered
+    #[inline]
+    pub fn return_value(&self) -> Option<&G::Return> {
+        if self.peeked.is_some() {
+            return None;
+        }
+        self.inner.return_value()
+    }
+}
+
+impl<G: Generator + Unpin + Clone> Clone for PeekableReturn<G>
+where
+    G::Yield: Clone,
+    G::Return: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        PeekableReturn {
+            inner: self.inner.clone(),
+            peeked: self.peeked.clone(),
+        }
+    }
+}
+
+impl<G: Generator + Unpin + fmt::Debug> fmt::Debug for PeekableReturn<G>
+where
+    G::Yield: fmt::Debug,
+    G::Return: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PeekableReturn")
+            .field("inner", &self.inner)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+impl<G: Generator + Unpin> Iterator for &mut PeekableReturn<G> {
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(y) => Some(y),
+            None => (&mut self.inner).next(),
+        }
+    }
+}
+
+impl<G: Generator + Unpin> FusedIterator for &mut PeekableReturn<G> {}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn peek_last() {
+        let mut g = gen_iter_return!({
+            yield 1;
+            yield 2;
+            return "done";
+        }).peekable_return();
+
+        assert_eq!(g.peek(), Some(&1));
+        assert_eq!(g.peek(), Some(&1));
+        assert_eq!((&mut g).next(), Some(1));
+
+        assert_eq!(g.peek(), Some(&2));
+        assert!(!g.is_done());
+        assert_eq!(g.return_value(), None);
+        let mut g = g.return_or_self().unwrap_err();
+        assert_eq!(g.peek(), Some(&2));
+
+        assert_eq!((&mut g).next(), Some(2));
+        assert!(!g.is_done());
+        assert_eq!(g.peek(), None);
+        assert!(g.is_done());
+        assert_eq!(g.return_value(), Some(&"done"));
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some("done"));
+    }
+
+    #[test]
+    fn iterate_after_peek() {
+        let mut g = gen_iter_return!({
+            for i in 0..3 {
+                yield i;
+            }
+            return 3;
+        }).peekable_return();
+
+        assert_eq!(g.peek(), Some(&0));
+        let mut sum = 0;
+        for x in &mut g {
+            sum += x;
+        }
+        assert_eq!(sum, 3);
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some(3));
+    }
+}
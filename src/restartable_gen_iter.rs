@@ -0,0 +1,108 @@
+use core::ops::Generator;
+use core::iter::{Iterator, FusedIterator};
+use core::marker::Unpin;
+
+use GenIter;
+
+/// a [`GenIter`] keeping a copy of its generator as created, to restart it,
+/// created by [`GenIter::restartable`].
+///
+/// generators are `Clone` when their captured variables are, with the `generator_clone` feature.
+#[derive(Copy, Clone, Debug)]
+pub struct RestartableGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin + Clone,
+{
+    iter: GenIter<T>,
+    pristine: T,
+}
+
+impl<T> GenIter<T>
+where
+    T: Generator<Return = ()> + Unpin + Clone,
+{
+    /// create a [`RestartableGenIter`], cloning the generator before it is resumed
+    ///
+    /// ```
+    /// #![feature(generators, generator_clone)]
+    ///
+    /// use gen_iter::GenIter;
+    ///
+    /// let mut g = GenIter::restartable(|| {
+    ///     yield 1;
+    ///     yield 2;
+    /// });
+    ///
+    /// assert_eq!(g.by_ref().collect::<Vec<_>>(), [1, 2]);
+    /// g.restart();
+    /// assert_eq!(g.by_ref().collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn restartable(gen: T) -> RestartableGenIter<T> {
+        RestartableGenIter {
+            iter: GenIter::new(gen.clone()),
+            pristine: gen,
+        }
+    }
+}
+
+impl<T> RestartableGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin + Clone,
+{
+    /// drop the current generator and start again from a clone of the original one
+    #[inline]
+    pub fn restart(&mut self) {
+        self.iter = GenIter::new(self.pristine.clone());
+    }
+}
+
+impl<T> Iterator for RestartableGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin + Clone,
+{
+    type Item = T::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<T> FusedIterator for RestartableGenIter<T>
+where
+    T: Generator<Return = ()> + Unpin + Clone,
+{}
+
+#[cfg(test)]
+mod tests {
+    use GenIter;
+
+    #[test]
+    fn restart_after_done() {
+        let n = 4;
+        let mut g = GenIter::restartable(move || {
+            for i in 0..n {
+                yield i * i;
+            }
+        });
+
+        assert!(g.by_ref().eq([0, 1, 4, 9].iter().cloned()));
+        assert_eq!(g.next(), None);
+        g.restart();
+        assert!(g.by_ref().eq([0, 1, 4, 9].iter().cloned()));
+    }
+
+    #[test]
+    fn restart_midway() {
+        let mut g = GenIter::restartable(|| {
+            yield 'a';
+            yield 'b';
+        });
+
+        assert_eq!(g.next(), Some('a'));
+        g.restart();
+        assert_eq!(g.next(), Some('a'));
+        assert_eq!(g.next(), Some('b'));
+    }
+}
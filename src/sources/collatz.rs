@@ -0,0 +1,76 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIterReturn;
+
+/// the Collatz sequence from `start` down to `1`, both included,
+/// the generator returns the number of steps taken.
+///
+/// the sequence is not defined for `0`, `collatz(0)` yields nothing and returns `0`.
+///
+/// panics if a value of the sequence overflows `u64`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::collatz;
+///
+/// let mut g = collatz(6);
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+/// assert_eq!(g.return_or_self().ok(), Some(8));
+/// ```
+pub fn collatz(start: u64) -> GenIterReturn<impl Generator<Yield = u64, Return = u64> + Unpin> {
+    GenIterReturn::new(move || {
+        if start == 0 {
+            return 0;
+        }
+        let mut n = start;
+        let mut steps = 0;
+        loop {
+            yield n;
+            if n == 1 {
+                return steps;
+            }
+            n = if n % 2 == 1 {
+                n.checked_mul(3)
+                    .and_then(|n| n.checked_add(1))
+                    .expect("collatz sequence overflows u64")
+            } else {
+                n / 2
+            };
+            steps += 1;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collatz;
+
+    #[test]
+    fn six() {
+        let mut g = collatz(6);
+
+        assert!((&mut g).eq([6, 3, 10, 5, 16, 8, 4, 2, 1].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(8));
+    }
+
+    #[test]
+    fn one_and_zero() {
+        let mut g = collatz(1);
+        assert!((&mut g).eq([1].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(0));
+
+        let mut g = collatz(0);
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(0));
+    }
+
+    #[test]
+    fn twenty_seven() {
+        let mut g = collatz(27);
+
+        assert_eq!((&mut g).max(), Some(9232));
+        assert_eq!(g.return_or_self().ok(), Some(111));
+    }
+}
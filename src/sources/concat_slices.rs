@@ -0,0 +1,44 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator over the bytes of all `slices` in order, empty slices are skipped.
+///
+/// the generator only borrows the slices, not its own locals,
+/// so it is a movable generator and needs no pinning.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::concat_slices;
+///
+/// let slices: &[&[u8]] = &[b"ab", b"", b"c"];
+/// assert_eq!(concat_slices(slices).collect::<Vec<_>>(), b"abc");
+/// ```
+pub fn concat_slices<'a>(slices: &'a [&'a [u8]]) -> GenIter<impl Generator<Yield = u8, Return = ()> + Unpin + 'a> {
+    GenIter::new(move || {
+        for slice in slices {
+            for &b in slice.iter() {
+                yield b;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::concat_slices;
+
+    #[test]
+    fn empty_slice_in_the_middle() {
+        let slices: &[&[u8]] = &[&[1, 2], &[], &[3, 4, 5]];
+
+        assert!(concat_slices(slices).eq(1..6));
+    }
+
+    #[test]
+    fn no_slices() {
+        assert_eq!(concat_slices(&[]).next(), None);
+    }
+}
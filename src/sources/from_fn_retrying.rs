@@ -0,0 +1,99 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIterReturn;
+
+/// create a [`GenIterReturn`] from a closure that may fail,
+/// retrying it up to `max_retries` times in a row after an error.
+///
+/// `Ok(Some(y))` is yielded, `Ok(None)` completes the generator with `Ok(())`,
+/// and the error after `max_retries` failed retries completes it with `Err(e)`.
+/// the retry count is reset by each success.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::from_fn_retrying;
+///
+/// let mut calls = 0;
+/// let mut g = from_fn_retrying(|| {
+///     calls += 1;
+///     match calls {
+///         1 | 3 => Err("flaky"),
+///         2 | 4 => Ok(Some(calls)),
+///         _ => Ok(None),
+///     }
+/// }, 1);
+///
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [2, 4]);
+/// assert_eq!(g.return_or_self().ok(), Some(Ok(())));
+/// ```
+pub fn from_fn_retrying<Y, E, F>(mut f: F, max_retries: usize) -> GenIterReturn<impl Generator<Yield = Y, Return = Result<(), E>> + Unpin>
+where
+    F: FnMut() -> Result<Option<Y>, E>,
+{
+    GenIterReturn::new(move || {
+        let mut retries = 0;
+        loop {
+            match f() {
+                Ok(Some(y)) => {
+                    retries = 0;
+                    yield y;
+                },
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    if retries == max_retries {
+                        return Err(e);
+                    }
+                    retries += 1;
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_fn_retrying;
+
+    #[test]
+    fn recovers() {
+        let mut calls = 0;
+        let mut g = from_fn_retrying(|| {
+            calls += 1;
+            match calls {
+                1 | 2 => Err(calls),
+                3 => Ok(Some('a')),
+                _ => Ok(None),
+            }
+        }, 2);
+
+        assert!((&mut g).eq(['a'].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(Ok(())));
+    }
+
+    #[test]
+    fn exhausts_retries() {
+        let mut calls = 0;
+        let mut g = from_fn_retrying(|| {
+            calls += 1;
+            if calls == 1 {
+                Ok(Some(0))
+            } else {
+                Err(calls)
+            }
+        }, 2);
+
+        assert_eq!((&mut g).next(), Some(0));
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(Err(4)));
+    }
+
+    #[test]
+    fn no_retries() {
+        let mut g = from_fn_retrying(|| Err::<Option<u8>, _>("down"), 0);
+
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(Err("down")));
+    }
+}
@@ -0,0 +1,93 @@
+use core::ops::Generator;
+use core::iter::IntoIterator;
+use core::marker::Unpin;
+use core::cmp::{Ord, Reverse};
+use alloc::collections::BinaryHeap;
+
+use GenIter;
+
+/// the items in ascending order, popped one by one from a `BinaryHeap`.
+///
+/// the heap is built from `items` before returning, in `O(n)`,
+/// each yield then takes `O(log n)`, so taking only the smallest items is cheaper than a full sort.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::from_heap;
+///
+/// let smallest: Vec<_> = from_heap(vec![5, 1, 4, 2, 3]).take(2).collect();
+/// assert_eq!(smallest, [1, 2]);
+/// ```
+pub fn from_heap<T: Ord>(items: impl IntoIterator<Item = T>) -> GenIter<impl Generator<Yield = T, Return = ()> + Unpin> {
+    let mut heap: BinaryHeap<Reverse<T>> = items.into_iter().map(Reverse).collect();
+    GenIter::new(move || {
+        while let Some(Reverse(item)) = heap.pop() {
+            yield item;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::cmp::Ordering;
+    use alloc::vec::Vec;
+    use super::from_heap;
+
+    #[test]
+    fn sorted() {
+        let items = vec![9, 3, 7, 1, 8, 2, 2, 6];
+        let mut expected = items.clone();
+        expected.sort();
+
+        assert_eq!(from_heap(items).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(from_heap(Vec::<u8>::new()).next(), None);
+    }
+
+    /// counts its drops, to know how many items left the heap
+    struct Counted<'a>(u32, &'a Cell<usize>);
+
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    impl<'a> PartialEq for Counted<'a> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<'a> Eq for Counted<'a> {}
+
+    impl<'a> PartialOrd for Counted<'a> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<'a> Ord for Counted<'a> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn partial_consumption() {
+        let dropped = Cell::new(0);
+        let mut g = from_heap((0..10).rev().map(|i| Counted(i, &dropped)));
+
+        assert_eq!(g.next().map(|c| c.0), Some(0));
+        assert_eq!(g.next().map(|c| c.0), Some(1));
+        assert_eq!(dropped.get(), 2);
+
+        drop(g);
+        assert_eq!(dropped.get(), 10);
+    }
+}
@@ -0,0 +1,60 @@
+use core::ops::Generator;
+use core::iter::IntoIterator;
+use core::marker::Unpin;
+
+use GenIterReturn;
+
+/// create a [`GenIterReturn`] from an iterator of `Result`s
+///
+/// the `Ok` values are yielded, the generator returns `Err(e)` at the first error,
+/// or `Ok(())` when the source iterator is exhausted without error.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::from_results;
+///
+/// let mut g = from_results(vec![Ok(1), Ok(2), Err("bad"), Ok(3)]);
+///
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [1, 2]);
+/// assert_eq!(g.return_or_self().ok(), Some(Err("bad")));
+/// ```
+pub fn from_results<I, T, E>(iter: I) -> GenIterReturn<impl Generator<Yield = T, Return = Result<(), E>> + Unpin>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    let iter = iter.into_iter();
+    GenIterReturn::new(move || {
+        for r in iter {
+            match r {
+                Ok(v) => yield v,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_results;
+
+    #[test]
+    fn error_in_the_middle() {
+        let mut g = from_results([Ok(1), Ok(2), Err("bad"), Ok(3)].iter().cloned());
+
+        assert_eq!((&mut g).next(), Some(1));
+        assert_eq!((&mut g).next(), Some(2));
+        assert_eq!((&mut g).next(), None);
+        assert!(g.is_done());
+        assert_eq!(g.return_or_self().ok(), Some(Err("bad")));
+    }
+
+    #[test]
+    fn no_error() {
+        let mut g = from_results([Ok::<_, ()>(1), Ok(2)].iter().cloned());
+
+        assert!((&mut g).eq([1, 2].iter().cloned()));
+        assert_eq!(g.return_or_self().ok(), Some(Ok(())));
+    }
+}
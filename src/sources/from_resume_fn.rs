@@ -0,0 +1,70 @@
+use core::ops::{Generator, GeneratorState};
+use core::marker::Unpin;
+
+use GenIterResume;
+
+/// create a [`GenIterResume`] from a closure,
+/// which gets each resume argument and returns the next state of the generator.
+///
+/// ```
+/// #![feature(generator_trait)]
+///
+/// use std::ops::GeneratorState;
+/// use gen_iter::from_resume_fn;
+///
+/// let mut total = 0;
+/// let mut g = from_resume_fn(move |x: i32| {
+///     if x < 0 {
+///         return GeneratorState::Complete(total);
+///     }
+///     total += x;
+///     GeneratorState::Yielded(total)
+/// });
+///
+/// assert_eq!(g.resume_with(1), Some(1));
+/// assert_eq!(g.resume_with(2), Some(3));
+/// assert_eq!(g.resume_with(-1), None);
+/// assert_eq!(g.return_or_self().ok(), Some(3));
+/// ```
+pub fn from_resume_fn<A, Y, R, F>(mut f: F) -> GenIterResume<impl Generator<A, Yield = Y, Return = R> + Unpin, A>
+where
+    F: FnMut(A) -> GeneratorState<Y, R>,
+{
+    GenIterResume::new(move |mut arg: A| {
+        loop {
+            match f(arg) {
+                GeneratorState::Yielded(y) => arg = yield y,
+                GeneratorState::Complete(r) => return r,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::GeneratorState;
+    use super::from_resume_fn;
+
+    #[test]
+    fn running_total() {
+        let mut total = 0;
+        let mut g = from_resume_fn(move |x: u32| {
+            total += x;
+            if total > 10 {
+                GeneratorState::Complete(total)
+            } else {
+                GeneratorState::Yielded(total)
+            }
+        });
+
+        assert_eq!(g.resume_with(1), Some(1));
+        assert_eq!(g.resume_with(2), Some(3));
+        assert_eq!(g.resume_with(3), Some(6));
+        assert!(!g.is_done());
+
+        assert_eq!(g.resume_with(5), None);
+        assert!(g.is_done());
+        assert_eq!(g.resume_with(1), None); // it won't panic when resumed after done
+        assert_eq!(g.return_or_self().ok(), Some(11));
+    }
+}
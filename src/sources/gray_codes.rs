@@ -0,0 +1,60 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// the `2^bits` values of the `bits`-bit reflected Gray code,
+/// consecutive values differ in exactly one bit.
+///
+/// `gray_codes(0)` yields a single `0`.
+///
+/// panics if `bits` is greater than `32`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::gray_codes;
+///
+/// assert_eq!(gray_codes(3).collect::<Vec<_>>(), [0, 1, 3, 2, 6, 7, 5, 4]);
+/// ```
+pub fn gray_codes(bits: u32) -> GenIter<impl Generator<Yield = u32, Return = ()> + Unpin> {
+    assert!(bits <= 32, "gray_codes supports at most 32 bits");
+    GenIter::new(move || {
+        let count = 1u64 << bits;
+        let mut i = 0u64;
+        while i < count {
+            yield (i ^ (i >> 1)) as u32;
+            i += 1;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gray_codes;
+
+    #[test]
+    fn two_bits() {
+        assert!(gray_codes(2).eq([0, 1, 3, 2].iter().cloned()));
+    }
+
+    #[test]
+    fn zero_bits() {
+        assert!(gray_codes(0).eq([0].iter().cloned()));
+    }
+
+    #[test]
+    fn one_bit_changes() {
+        let mut prev = 0;
+        for (i, g) in gray_codes(8).enumerate().skip(1) {
+            assert_eq!((g ^ prev).count_ones(), 1, "at {}", i);
+            prev = g;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_bits() {
+        gray_codes(33);
+    }
+}
@@ -0,0 +1,60 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// the endless sequence starting with `a0, a1` where each next term is
+/// `coeffs.0 * prev + coeffs.1 * prev2`, like Fibonacci with `(0, 1)` and `(1, 1)`,
+/// Lucas with `(2, 1)` and `(1, 1)`, or Pell with `(0, 1)` and `(2, 1)`.
+///
+/// the arithmetic wraps around on overflow, so the terms are exact as long as they fit in `u64`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::linear_recurrence;
+///
+/// let pell: Vec<_> = linear_recurrence(0, 1, (2, 1)).take(6).collect();
+/// assert_eq!(pell, [0, 1, 2, 5, 12, 29]);
+/// ```
+pub fn linear_recurrence(a0: u64, a1: u64, coeffs: (u64, u64)) -> GenIter<impl Generator<Yield = u64, Return = ()> + Unpin> {
+    GenIter::new(move || {
+        let (mut prev2, mut prev) = (a0, a1);
+        yield prev2;
+        loop {
+            yield prev;
+            let next = coeffs.0.wrapping_mul(prev).wrapping_add(coeffs.1.wrapping_mul(prev2));
+            prev2 = prev;
+            prev = next;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::linear_recurrence;
+
+    #[test]
+    fn fibonacci() {
+        let fib = linear_recurrence(0, 1, (1, 1)).take(10);
+
+        assert!(fib.eq([0, 1, 1, 2, 3, 5, 8, 13, 21, 34].iter().cloned()));
+    }
+
+    #[test]
+    fn lucas() {
+        let lucas = linear_recurrence(2, 1, (1, 1)).take(6);
+
+        assert!(lucas.eq([2, 1, 3, 4, 7, 11].iter().cloned()));
+    }
+
+    #[test]
+    fn wraps() {
+        // fib(93) overflows u64
+        let fib93 = linear_recurrence(0, 1, (1, 1)).nth(93).unwrap();
+        let fib91 = 4660046610375530309u64;
+        let fib92 = 7540113804746346429u64;
+
+        assert_eq!(fib93, fib91.wrapping_add(fib92));
+    }
+}
@@ -0,0 +1,56 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// an iterator over the lines of `s`, split on `'\n'`.
+///
+/// a trailing newline does not start an empty last line,
+/// the generator only borrows `s`, so it is movable and needs no pinning.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::lines;
+///
+/// assert_eq!(lines("a\n\nb\n").collect::<Vec<_>>(), ["a", "", "b"]);
+/// ```
+pub fn lines<'a>(s: &'a str) -> GenIter<impl Generator<Yield = &'a str, Return = ()> + Unpin + 'a> {
+    GenIter::new(move || {
+        let mut rest = s;
+        while !rest.is_empty() {
+            match rest.find('\n') {
+                Some(i) => {
+                    yield &rest[..i];
+                    rest = &rest[i + 1..];
+                },
+                None => {
+                    yield rest;
+                    rest = "";
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lines;
+
+    #[test]
+    fn trailing_newline() {
+        assert!(lines("one\n\nthree\n").eq(["one", "", "three"].iter().cloned()));
+    }
+
+    #[test]
+    fn no_trailing_newline() {
+        assert!(lines("one\n\nthree").eq(["one", "", "three"].iter().cloned()));
+    }
+
+    #[test]
+    fn same_as_str_lines() {
+        for s in &["", "\n", "\n\n", "a", "a\nb", " a \n b \n"] {
+            assert!(lines(s).eq(s.lines()));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use core::convert::TryFrom;
+use alloc::vec::Vec;
+
+use GenIterReturn;
+
+/// yield the coins making exactly `amount` with the fewest coins,
+/// the generator returns whether exact change is possible.
+///
+/// unlike a greedy choice it always finds the change if there is one,
+/// it yields nothing and returns `false` otherwise.
+/// the solution is computed on the first resume, using memory proportional to `amount`,
+/// it also yields nothing and returns `false` if that memory cannot be allocated.
+/// coins of value `0` are ignored.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::make_change;
+///
+/// let mut g = make_change(6, &[1, 3, 4]);
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [3, 3]); // greedy would give 4, 1, 1
+/// assert_eq!(g.return_or_self().ok(), Some(true));
+/// ```
+pub fn make_change<'a>(amount: u64, coins: &'a [u64]) -> GenIterReturn<impl Generator<Yield = u64, Return = bool> + Unpin + 'a> {
+    GenIterReturn::new(move || {
+        let amount = match usize::try_from(amount) {
+            Ok(amount) => amount,
+            Err(_) => return false,
+        };
+        let len = match amount.checked_add(1) {
+            Some(len) => len,
+            None => return false,
+        };
+        // `last[v]` is the last coin of the best change for `v`, `0` if there is none
+        let mut count = Vec::new();
+        let mut last = Vec::new();
+        if count.try_reserve_exact(len).is_err() || last.try_reserve_exact(len).is_err() {
+            return false;
+        }
+        count.resize(len, usize::MAX);
+        last.resize(len, 0u64);
+        count[0] = 0;
+        for v in 1..=amount {
+            for &c in coins {
+                let c_len = match usize::try_from(c) {
+                    Ok(c_len) => c_len,
+                    Err(_) => continue,
+                };
+                if c == 0 || c_len > v || count[v - c_len] == usize::MAX {
+                    continue;
+                }
+                if count[v - c_len] + 1 < count[v] {
+                    count[v] = count[v - c_len] + 1;
+                    last[v] = c;
+                }
+            }
+        }
+        if count[amount] == usize::MAX {
+            return false;
+        }
+
+        let mut v = amount;
+        while v > 0 {
+            let c = last[v];
+            yield c;
+            v -= c as usize;
+        }
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use super::make_change;
+
+    #[test]
+    fn solvable() {
+        let mut g = make_change(63, &[25, 10, 5, 1]);
+        let mut coins: Vec<_> = (&mut g).collect();
+        coins.sort();
+
+        assert_eq!(coins, [1, 1, 1, 10, 25, 25]);
+        assert_eq!(g.return_or_self().ok(), Some(true));
+    }
+
+    #[test]
+    fn unsolvable() {
+        let mut g = make_change(7, &[2, 4]);
+
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(false));
+    }
+
+    #[test]
+    fn zero_amount() {
+        let mut g = make_change(0, &[]);
+
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(true));
+    }
+
+    #[test]
+    fn amount_too_large() {
+        let mut g = make_change(u64::MAX, &[1]);
+
+        assert_eq!((&mut g).next(), None);
+        assert_eq!(g.return_or_self().ok(), Some(false));
+    }
+}
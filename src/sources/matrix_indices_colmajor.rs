@@ -0,0 +1,50 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// the `(row, col)` indices of a `rows` by `cols` matrix in column-major order,
+/// going down each column before moving to the next one.
+///
+/// nothing is yielded if `rows` or `cols` is `0`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::matrix_indices_colmajor;
+///
+/// let indices: Vec<_> = matrix_indices_colmajor(2, 2).collect();
+/// assert_eq!(indices, [(0, 0), (1, 0), (0, 1), (1, 1)]);
+/// ```
+pub fn matrix_indices_colmajor(rows: usize, cols: usize) -> GenIter<impl Generator<Yield = (usize, usize), Return = ()> + Unpin> {
+    GenIter::new(move || {
+        // an empty column would still be visited `cols` times
+        if rows == 0 || cols == 0 {
+            return;
+        }
+        for col in 0..cols {
+            for row in 0..rows {
+                yield (row, col);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matrix_indices_colmajor;
+
+    #[test]
+    fn two_by_three() {
+        let expected = [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)];
+
+        assert!(matrix_indices_colmajor(2, 3).eq(expected.iter().cloned()));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(matrix_indices_colmajor(0, 3).next(), None);
+        assert_eq!(matrix_indices_colmajor(3, 0).next(), None);
+        assert_eq!(matrix_indices_colmajor(0, usize::MAX).next(), None);
+    }
+}
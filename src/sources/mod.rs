@@ -0,0 +1,74 @@
+//! functions creating generator based iterators from other sources
+
+mod from_results;
+pub use self::from_results::*;
+
+#[cfg(feature = "alloc")]
+mod pascals_triangle;
+#[cfg(feature = "alloc")]
+pub use self::pascals_triangle::*;
+
+#[cfg(feature = "alloc")]
+mod primes;
+#[cfg(feature = "alloc")]
+pub use self::primes::*;
+
+mod concat_slices;
+pub use self::concat_slices::*;
+
+mod from_resume_fn;
+pub use self::from_resume_fn::*;
+
+mod from_fn_retrying;
+pub use self::from_fn_retrying::*;
+
+mod collatz;
+pub use self::collatz::*;
+
+mod lines;
+pub use self::lines::*;
+
+#[cfg(feature = "alloc")]
+mod make_change;
+#[cfg(feature = "alloc")]
+pub use self::make_change::*;
+
+mod with_computed_return;
+pub use self::with_computed_return::*;
+
+mod spiral;
+pub use self::spiral::*;
+
+mod gray_codes;
+pub use self::gray_codes::*;
+
+#[cfg(feature = "std")]
+mod schedule;
+#[cfg(feature = "std")]
+pub use self::schedule::*;
+
+#[cfg(feature = "alloc")]
+mod from_heap;
+#[cfg(feature = "alloc")]
+pub use self::from_heap::*;
+
+#[cfg(feature = "rand")]
+mod random_walk;
+#[cfg(feature = "rand")]
+pub use self::random_walk::*;
+
+#[cfg(feature = "alloc")]
+mod power_set;
+#[cfg(feature = "alloc")]
+pub use self::power_set::*;
+
+mod linear_recurrence;
+pub use self::linear_recurrence::*;
+
+#[cfg(feature = "std")]
+mod replay_log;
+#[cfg(feature = "std")]
+pub use self::replay_log::*;
+
+mod matrix_indices_colmajor;
+pub use self::matrix_indices_colmajor::*;
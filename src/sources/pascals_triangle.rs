@@ -0,0 +1,70 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// an iterator over the rows of Pascal's triangle,
+/// each row is computed from the previous one.
+///
+/// row `67` is the last one whose values fit in a `u64`,
+/// the iterator ends after it instead of overflowing.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::pascals_triangle;
+///
+/// let rows: Vec<_> = pascals_triangle().take(4).collect();
+/// assert_eq!(rows, [vec![1], vec![1, 1], vec![1, 2, 1], vec![1, 3, 3, 1]]);
+///
+/// assert_eq!(pascals_triangle().count(), 68);
+/// ```
+pub fn pascals_triangle() -> GenIter<impl Generator<Yield = Vec<u64>, Return = ()> + Unpin> {
+    GenIter::new(|| {
+        let mut row = vec![1u64];
+        loop {
+            let next = next_row(&row);
+            yield row;
+            row = match next {
+                Some(r) => r,
+                None => return,
+            };
+        }
+    })
+}
+
+/// the row following `row`, `None` on overflow
+fn next_row(row: &[u64]) -> Option<Vec<u64>> {
+    let mut next = Vec::with_capacity(row.len() + 1);
+    next.push(1);
+    for w in row.windows(2) {
+        next.push(w[0].checked_add(w[1])?);
+    }
+    next.push(1);
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pascals_triangle;
+
+    #[test]
+    fn first_five_rows() {
+        let mut rows = pascals_triangle();
+
+        assert_eq!(rows.next().unwrap(), [1]);
+        assert_eq!(rows.next().unwrap(), [1, 1]);
+        assert_eq!(rows.next().unwrap(), [1, 2, 1]);
+        assert_eq!(rows.next().unwrap(), [1, 3, 3, 1]);
+        assert_eq!(rows.next().unwrap(), [1, 4, 6, 4, 1]);
+    }
+
+    #[test]
+    fn ends_before_overflow() {
+        let last = pascals_triangle().last().unwrap();
+
+        assert_eq!(last.len(), 68);
+        assert_eq!(last[33], 14226520737620288370);
+    }
+}
@@ -0,0 +1,67 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// all the subsets of `items`, counting a bitmask from `0` to `2^len - 1`,
+/// the subset of the mask `m` holds the items at the set bits of `m`, in order.
+///
+/// the empty set is yielded first, and `items` itself last.
+///
+/// panics if `items` has more than `63` elements.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::power_set;
+///
+/// let sets: Vec<Vec<char>> = power_set(&['a', 'b']).collect();
+/// assert_eq!(sets, [vec![], vec!['a'], vec!['b'], vec!['a', 'b']]);
+/// ```
+pub fn power_set<'a, T: Clone>(items: &'a [T]) -> GenIter<impl Generator<Yield = Vec<T>, Return = ()> + Unpin + 'a> {
+    assert!(items.len() <= 63, "power_set supports at most 63 items");
+    GenIter::new(move || {
+        let count = 1u64 << items.len();
+        for mask in 0..count {
+            let set = items
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect();
+            yield set;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use super::power_set;
+
+    #[test]
+    fn two_items() {
+        let sets: Vec<Vec<i32>> = power_set(&[1, 2]).collect();
+
+        assert_eq!(sets, [vec![], vec![1], vec![2], vec![1, 2]]);
+    }
+
+    #[test]
+    fn empty() {
+        let sets: Vec<Vec<u8>> = power_set(&[]).collect();
+
+        assert_eq!(sets, [Vec::<u8>::new()]);
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(power_set(&[0; 10]).count(), 1024);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_items() {
+        power_set(&[0; 64]);
+    }
+}
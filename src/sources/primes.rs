@@ -0,0 +1,57 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use GenIter;
+
+/// an endless iterator over the prime numbers, using an incremental sieve of Eratosthenes.
+///
+/// the sieve maps each upcoming composite number to the primes dividing it,
+/// it uses a `BTreeMap` so it only needs `alloc`.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::primes;
+///
+/// assert_eq!(primes().take(5).collect::<Vec<_>>(), [2, 3, 5, 7, 11]);
+/// ```
+pub fn primes() -> GenIter<impl Generator<Yield = u64, Return = ()> + Unpin> {
+    GenIter::new(|| {
+        let mut composites: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+        let mut n = 2u64;
+        loop {
+            match composites.remove(&n) {
+                Some(factors) => {
+                    for p in factors {
+                        composites.entry(n + p).or_default().push(p);
+                    }
+                },
+                None => {
+                    // squares above `u64::MAX` are never reached
+                    if let Some(square) = n.checked_mul(n) {
+                        composites.insert(square, vec![n]);
+                    }
+                    yield n;
+                },
+            }
+            n += 1;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::primes;
+
+    #[test]
+    fn first_ten() {
+        assert!(primes().take(10).eq([2, 3, 5, 7, 11, 13, 17, 19, 23, 29].iter().cloned()));
+    }
+
+    #[test]
+    fn thousandth() {
+        assert_eq!(primes().nth(999), Some(7919));
+    }
+}
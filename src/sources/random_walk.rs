@@ -0,0 +1,62 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use rand::Rng;
+
+use GenIter;
+
+/// an endless random walk from `start`, included,
+/// each step goes up or down by `1` with the same probability.
+///
+/// ```
+/// #![feature(generators)]
+/// # extern crate rand;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use gen_iter::random_walk;
+///
+/// let walk: Vec<i64> = random_walk(0, StdRng::seed_from_u64(7)).take(10).collect();
+///
+/// assert_eq!(walk[0], 0);
+/// assert!(walk.windows(2).all(|w| (w[1] - w[0]).abs() == 1));
+/// ```
+pub fn random_walk<R: Rng>(start: i64, mut rng: R) -> GenIter<impl Generator<Yield = i64, Return = ()> + Unpin> {
+    GenIter::new(move || {
+        let mut pos = start;
+        loop {
+            yield pos;
+            if rng.gen() {
+                pos += 1;
+            } else {
+                pos -= 1;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use super::random_walk;
+
+    #[test]
+    fn unit_steps() {
+        let mut walk = random_walk(-5, StdRng::seed_from_u64(42));
+
+        let mut prev = walk.next().unwrap();
+        assert_eq!(prev, -5);
+        for pos in walk.take(100) {
+            assert_eq!((pos - prev).abs(), 1);
+            prev = pos;
+        }
+    }
+
+    #[test]
+    fn seeded_walks_repeat() {
+        let a = random_walk(0, StdRng::seed_from_u64(1)).take(50);
+        let b = random_walk(0, StdRng::seed_from_u64(1)).take(50);
+
+        assert!(a.eq(b));
+    }
+}
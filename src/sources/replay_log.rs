@@ -0,0 +1,70 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use std::time::Duration;
+use std::thread;
+use std::vec::Vec;
+
+use GenIter;
+
+/// the values of a recorded event log, each `(delay, value)` coming `delay` after the previous one.
+///
+/// with `real_time` the thread sleeps for each delay before yielding the value,
+/// otherwise the values are yielded immediately, for fast tests.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use std::time::Duration;
+/// use gen_iter::replay_log;
+///
+/// let log = vec![(Duration::from_secs(1), "start"), (Duration::from_secs(5), "stop")];
+///
+/// assert_eq!(replay_log(log, false).collect::<Vec<_>>(), ["start", "stop"]);
+/// ```
+pub fn replay_log<T>(events: Vec<(Duration, T)>, real_time: bool) -> GenIter<impl Generator<Yield = T, Return = ()> + Unpin> {
+    GenIter::new(move || {
+        for (delay, value) in events {
+            if real_time {
+                thread::sleep(delay);
+            }
+            yield value;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::replay_log;
+
+    #[test]
+    fn immediate() {
+        let log = vec![
+            (Duration::from_secs(3600), 1),
+            (Duration::from_secs(3600), 2),
+            (Duration::from_secs(3600), 3),
+        ];
+        let start = Instant::now();
+
+        assert!(replay_log(log, false).eq(1..4));
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn real_time() {
+        let log = vec![
+            (Duration::from_millis(0), 'a'),
+            (Duration::from_millis(30), 'b'),
+            (Duration::from_millis(30), 'c'),
+        ];
+        let start = Instant::now();
+        let mut g = replay_log(log, true);
+
+        assert_eq!(g.next(), Some('a'));
+        assert_eq!(g.next(), Some('b'));
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert_eq!(g.next(), Some('c'));
+        assert!(start.elapsed() >= Duration::from_millis(60));
+        assert_eq!(g.next(), None);
+    }
+}
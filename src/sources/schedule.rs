@@ -0,0 +1,76 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+use std::time::{Duration, Instant};
+
+use GenIter;
+
+/// `count` instants spaced by `interval` from `start` included,
+/// computed without waiting for them.
+///
+/// stops early when the next instant cannot be represented.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use std::time::{Duration, Instant};
+/// use gen_iter::schedule;
+///
+/// let start = Instant::now();
+/// let times: Vec<_> = schedule(start, Duration::from_secs(60), 3).collect();
+///
+/// assert_eq!(times, [start, start + Duration::from_secs(60), start + Duration::from_secs(120)]);
+/// ```
+pub fn schedule(start: Instant, interval: Duration, count: usize) -> GenIter<impl Generator<Yield = Instant, Return = ()> + Unpin> {
+    GenIter::new(move || {
+        let mut at = start;
+        for i in 0..count {
+            if i > 0 {
+                at = match at.checked_add(interval) {
+                    Some(at) => at,
+                    None => return,
+                };
+            }
+            yield at;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::schedule;
+
+    #[test]
+    fn spacing() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(250);
+        let mut count = 0;
+        let mut prev = None;
+        for t in schedule(start, interval, 5) {
+            match prev {
+                None => assert_eq!(t, start),
+                Some(p) => assert_eq!(t - p, interval),
+            }
+            prev = Some(t);
+            count += 1;
+        }
+
+        assert_eq!(count, 5);
+        assert_eq!(prev, Some(start + interval * 4));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(schedule(Instant::now(), Duration::from_secs(1), 0).next(), None);
+    }
+
+    #[test]
+    fn overflow_stops() {
+        let start = Instant::now();
+        let mut g = schedule(start, Duration::MAX, 2);
+
+        assert_eq!(g.next(), Some(start));
+        assert_eq!(g.next(), None);
+        assert_eq!(g.next(), None);
+    }
+}
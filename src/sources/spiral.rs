@@ -0,0 +1,109 @@
+use core::ops::Generator;
+use core::marker::Unpin;
+
+use GenIter;
+
+/// the `(x, y)` coordinates of a `width` x `height` grid in clockwise spiral order,
+/// starting from the top-left corner `(0, 0)` and going right first.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::spiral;
+///
+/// assert_eq!(
+///     spiral(3, 2).collect::<Vec<_>>(),
+///     [(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1)]
+/// );
+/// ```
+pub fn spiral(width: usize, height: usize) -> GenIter<impl Generator<Yield = (usize, usize), Return = ()> + Unpin> {
+    GenIter::new(move || {
+        if width == 0 || height == 0 {
+            return;
+        }
+        // inclusive bounds of the ring not visited yet
+        let (mut left, mut right, mut top, mut bottom) = (0, width - 1, 0, height - 1);
+        loop {
+            for x in left..=right {
+                yield (x, top);
+            }
+            if top == bottom {
+                return;
+            }
+            top += 1;
+            for y in top..=bottom {
+                yield (right, y);
+            }
+            if left == right {
+                return;
+            }
+            right -= 1;
+            for x in (left..=right).rev() {
+                yield (x, bottom);
+            }
+            if top == bottom {
+                return;
+            }
+            bottom -= 1;
+            for y in (top..=bottom).rev() {
+                yield (left, y);
+            }
+            if left == right {
+                return;
+            }
+            left += 1;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spiral;
+
+    #[test]
+    fn three_by_three() {
+        let expected = [
+            (0, 0), (1, 0), (2, 0),
+            (2, 1), (2, 2),
+            (1, 2), (0, 2),
+            (0, 1),
+            (1, 1),
+        ];
+        assert!(spiral(3, 3).eq(expected.iter().cloned()));
+    }
+
+    #[test]
+    fn four_by_three() {
+        let expected = [
+            (0, 0), (1, 0), (2, 0), (3, 0),
+            (3, 1), (3, 2),
+            (2, 2), (1, 2), (0, 2),
+            (0, 1),
+            (1, 1), (2, 1),
+        ];
+        assert!(spiral(4, 3).eq(expected.iter().cloned()));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(spiral(3, 1).eq([(0, 0), (1, 0), (2, 0)].iter().cloned()));
+        assert!(spiral(1, 3).eq([(0, 0), (0, 1), (0, 2)].iter().cloned()));
+        assert!(spiral(1, 1).eq([(0, 0)].iter().cloned()));
+        assert_eq!(spiral(0, 3).next(), None);
+        assert_eq!(spiral(3, 0).next(), None);
+    }
+
+    #[test]
+    fn visits_every_cell_once() {
+        for &(w, h) in &[(2, 5), (5, 2), (4, 4), (7, 3)] {
+            let mut seen = [[false; 8]; 8];
+            let mut count = 0;
+            for (x, y) in spiral(w, h) {
+                assert!(!seen[y][x]);
+                seen[y][x] = true;
+                count += 1;
+            }
+            assert_eq!(count, w * h);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use core::ops::Generator;
+use core::iter::IntoIterator;
+use core::marker::Unpin;
+
+use GenIterReturn;
+
+/// wrap any iterator, like an adapter chain over a [`GenIter`](crate::GenIter),
+/// into a [`GenIterReturn`] whose return value is `compute()`.
+///
+/// `compute` is called once the iterator is exhausted.
+///
+/// ```
+/// #![feature(generators)]
+///
+/// use gen_iter::{gen_iter, with_computed_return};
+///
+/// let evens = gen_iter!({
+///     for i in 0..6 {
+///         yield i;
+///     }
+/// }).filter(|i| i % 2 == 0);
+///
+/// let mut g = with_computed_return(evens, || "done");
+/// assert_eq!((&mut g).collect::<Vec<_>>(), [0, 2, 4]);
+/// assert_eq!(g.return_or_self().ok(), Some("done"));
+/// ```
+pub fn with_computed_return<I, R, F>(iter: I, compute: F) -> GenIterReturn<impl Generator<Yield = I::Item, Return = R> + Unpin>
+where
+    I: IntoIterator,
+    F: FnOnce() -> R,
+{
+    let iter = iter.into_iter();
+    GenIterReturn::new(move || {
+        for x in iter {
+            yield x;
+        }
+        compute()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use super::with_computed_return;
+
+    #[test]
+    fn computed_after_full_iteration() {
+        let computed = Cell::new(false);
+        let mut g = with_computed_return(gen_iter!({
+            yield 1;
+            yield 2;
+        }).map(|x| x * 10), || {
+            computed.set(true);
+            42
+        });
+
+        assert_eq!((&mut g).next(), Some(10));
+        assert_eq!((&mut g).next(), Some(20));
+        assert!(!computed.get());
+        assert!(!g.is_done());
+
+        assert_eq!((&mut g).next(), None);
+        assert!(computed.get());
+        assert_eq!(g.return_or_self().ok(), Some(42));
+    }
+}
@@ -0,0 +1,125 @@
+use core::ops::Generator;
+use core::iter::Iterator;
+use core::marker::Unpin;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use futures_core::stream::FusedStream;
+
+use GenIter;
+
+/// a `Stream` over the yields of a generator, created by [`GenIter::into_stream`].
+///
+/// the generator is synchronous, so `poll_next` resumes it and is always ready,
+/// it never returns `Poll::Pending`.
+///
+/// ```
+/// #![feature(generators)]
+/// # extern crate futures;
+///
+/// use futures::executor::block_on;
+/// use futures::stream::StreamExt;
+/// use gen_iter::gen_iter;
+///
+/// let s = gen_iter!({
+///     yield 1;
+///     yield 2;
+/// }).into_stream();
+///
+/// assert_eq!(block_on(s.map(|x| x * 10).collect::<Vec<_>>()), [10, 20]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct GenStream<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    iter: GenIter<G>,
+}
+
+impl<G> GenStream<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    pub fn new(gen: G) -> Self {
+        GenStream {
+            iter: GenIter::new(gen),
+        }
+    }
+}
+
+impl<G> GenIter<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    /// use the generator as a `Stream`
+    #[inline]
+    pub fn into_stream(self) -> GenStream<G> {
+        GenStream { iter: self }
+    }
+}
+
+impl<G> From<GenIter<G>> for GenStream<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    fn from(iter: GenIter<G>) -> Self {
+        iter.into_stream()
+    }
+}
+
+impl<G> Stream for GenStream<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    type Item = G::Yield;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().iter.next())
+    }
+}
+
+impl<G> FusedStream for GenStream<G>
+where
+    G: Generator<Return = ()> + Unpin,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.iter.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::stream::{FusedStream, StreamExt};
+    use super::GenStream;
+
+    #[test]
+    fn collect() {
+        use alloc::vec::Vec;
+
+        let s = gen_iter!({
+            for i in 0..5 {
+                yield i;
+            }
+        }).into_stream();
+
+        assert_eq!(block_on(s.collect::<Vec<_>>()), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn new_and_terminated() {
+        let mut s = GenStream::new(|| {
+            yield 'a';
+        });
+
+        assert!(!s.is_terminated());
+        assert_eq!(block_on(s.next()), Some('a'));
+        assert_eq!(block_on(s.next()), None);
+        assert!(s.is_terminated());
+        assert_eq!(block_on(s.next()), None);
+    }
+}
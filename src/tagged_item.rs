@@ -0,0 +1,7 @@
+/// an item of a generator emitting both data and control events.
+#[cfg_attr(feature = "alloc", doc = "", doc = "see [`GenIter::partition_tagged`](crate::GenIter::partition_tagged).")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaggedItem<D, C> {
+    Data(D),
+    Control(C),
+}